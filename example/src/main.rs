@@ -1,3 +1,53 @@
+use std::env;
+use std::process;
+
+use aoc::error::Result;
+use aoc::read_file;
+use aoc::registry::{self, Target};
+use aoc::solution::{Part, Solution};
+use aoc::y2020::day08::{self, DayEight, Opcode};
+
+// Uses the solvers as a library: pokes at day 8's parsed boot code, then runs
+// day 8 through the registry like the CLI does.
+//   cargo run -p example -- [FILE]    (default data/2020/8.txt)
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().collect();
+    let filename = args.get(1).map_or("data/2020/8.txt", String::as_str);
+
+    if let Err(e) = run(filename) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(filename: &str) -> Result<()> {
+    let input = read_file(filename)?;
+
+    let day = DayEight::parse(&input)?;
+    let instructions = day.instructions();
+    let jumps = instructions
+        .iter()
+        .filter(|op| matches!(op, Opcode::Jmp(_)))
+        .count();
+    println!(
+        "{} instructions, {} of them jumps",
+        instructions.len(),
+        jumps
+    );
+
+    match day08::run_program(instructions) {
+        Ok(acc) => println!("Terminates with acc {}", acc),
+        Err(acc) => println!("Loops, with acc {} just before repeating", acc),
+    }
+
+    let days = registry::days();
+    let target = Target {
+        year: 2020,
+        day: 8,
+        part: Part::Two,
+    };
+    let run = registry::find(&days, target)?.run(&input, target.part)?;
+    println!("Part two: {} (in {:.2?})", run.answer, run.total());
+
+    Ok(())
 }
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::input::Source;
+use crate::parallel;
+use crate::read_file;
+use crate::registry::{self, Day, Target};
+
+// Each year's answers live alongside its inputs
+pub fn answers_file(year: u32) -> String {
+    format!("data/{}/answers.toml", year)
+}
+
+/// Known-good answers, from a (very small subset of) TOML file like:
+///   7b = 220149
+///   21b = "qqskn,ccvnlbp"
+/// Keys without a year ("2020/7b") are for `year`.
+#[derive(Default)]
+pub struct Answers {
+    answers: HashMap<Target, String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str, year: u32) -> Result<Answers> {
+        let mut answers: HashMap<Target, String> = HashMap::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                Error::parse(format!("Expected 'key = value', found '{}'", line)).on_line(i + 1)
+            })?;
+            let target = Target::parse(key.trim(), year).ok_or_else(|| {
+                Error::parse(format!("Bad target '{}'", key.trim())).on_line(i + 1)
+            })?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            answers.insert(target, value.to_string());
+        }
+
+        Ok(Answers { answers })
+    }
+
+    // Merge in another file's answers
+    pub fn extend(&mut self, other: Answers) {
+        self.answers.extend(other.answers);
+    }
+
+    pub fn get(&self, target: Target) -> Option<&str> {
+        self.answers.get(&target).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String), // What we got instead
+    Missing,
+}
+
+/// Answers recorded for parts that aren't registered - a typo in the file, or
+/// a day that's gone.  Parts with no answer yet, like a day `new-day` has just
+/// made, are fine: `verify` lists them as missing.
+pub fn unregistered(days: &[Day], answers: &Answers) -> Vec<Target> {
+    let registered = registry::targets(days);
+    let mut unknown: Vec<Target> = answers
+        .answers
+        .keys()
+        .filter(|target| !registered.contains(target))
+        .copied()
+        .collect();
+    unknown.sort_unstable();
+    unknown
+}
+
+pub fn check(answers: &Answers, target: Target, answer: &str) -> Verdict {
+    match answers.get(target) {
+        Some(expected) if expected == answer => Verdict::Pass,
+        Some(_) => Verdict::Fail(answer.to_string()),
+        None => Verdict::Missing,
+    }
+}
+
+// e.g. "verify --answers my_answers.toml --jobs 4 1-10"
+// Returns the number of parts which failed
+pub fn main(days: &[Day], source: &Source, args: &[String]) -> Result<usize> {
+    let (jobs, args) = parallel::jobs_from_args(args)?;
+    let mut filename: Option<&str> = None;
+    let mut specs: Vec<&str> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                filename = Some(
+                    args.next()
+                        .ok_or_else(|| Error::usage("--answers needs a file name"))?,
+                );
+            }
+            spec => specs.push(spec),
+        }
+    }
+    if specs.is_empty() {
+        specs.push("all");
+    }
+
+    let targets = registry::select_all(days, &specs)?;
+    source.check(&targets)?;
+
+    // One file for everything if we're given it, otherwise each year's own
+    let answers = match filename {
+        Some(filename) => Answers::parse(&read_file(filename)?, registry::latest_year(days))?,
+        None => {
+            let mut years: Vec<u32> = targets.iter().map(|t| t.year).collect();
+            years.sort_unstable();
+            years.dedup();
+
+            let mut answers = Answers::default();
+            for year in years {
+                answers.extend(Answers::parse(&read_file(&answers_file(year))?, year)?);
+            }
+            answers
+        }
+    };
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    let work = |&target: &Target| {
+        let day = registry::find(days, target)?;
+        source
+            .read(target.year, target.day)
+            .and_then(|input| day.run(&input, target.part))
+    };
+    parallel::for_each_ordered(&targets, jobs, work, |&target, result| {
+        let answer = match result {
+            Ok(run) => run.answer,
+            Err(e) => {
+                // A part which can't produce an answer at all is a failure
+                failed += 1;
+                println!("FAIL    {}: {}", target, e);
+                return;
+            }
+        };
+
+        match check(&answers, target, &answer) {
+            Verdict::Pass => {
+                passed += 1;
+                println!("PASS    {}: {}", target, answer);
+            }
+            Verdict::Fail(got) => {
+                failed += 1;
+                println!(
+                    "FAIL    {}: got {}, expected {}",
+                    target,
+                    got,
+                    answers.get(target).unwrap()
+                );
+            }
+            Verdict::Missing => {
+                missing += 1;
+                println!("MISSING {}: got {}", target, answer);
+            }
+        }
+    });
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    Ok(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# Comment\n\n7b = 220149\n21b = \"abc,def\"\n2019/1a = 3\n",
+            2020,
+        )
+        .unwrap();
+        let get = |key| answers.get(Target::parse(key, 2015).unwrap());
+        assert!(get("2020/7b") == Some("220149"));
+        assert!(get("2020/21b") == Some("abc,def"));
+        assert!(get("2019/1a") == Some("3"));
+        assert!(get("2020/7a").is_none());
+
+        let error = Answers::parse("7b = 1\n7c = 2", 2020).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Bad target '7c'");
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("1a = 514579", 2020).unwrap();
+        let one = Target::parse("2020/1a", 2020).unwrap();
+        let two = Target::parse("2020/1b", 2020).unwrap();
+        assert!(check(&answers, one, "514579") == Verdict::Pass);
+        assert!(check(&answers, one, "1") == Verdict::Fail("1".to_string()));
+        assert!(check(&answers, two, "1") == Verdict::Missing);
+    }
+
+    // Each year's answers file parses, and only has answers for registered
+    // parts
+    fn check_answers_files(days: &[Day], read: impl Fn(u32) -> Result<String>) {
+        let mut years: Vec<u32> = days.iter().map(|day| day.year).collect();
+        years.dedup();
+        for year in years {
+            let answers = Answers::parse(&read(year).unwrap(), year).unwrap();
+            assert!(unregistered(days, &answers).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_answers_file() {
+        check_answers_files(&registry::days(), |year| read_file(&answers_file(year)));
+
+        let answers = Answers::parse("1a = 1\n2019/1b = 2", 2020).unwrap();
+        let unknown = unregistered(&registry::days(), &answers);
+        assert!(unknown == [Target::parse("2019/1b", 2020).unwrap()]);
+    }
+
+    #[test]
+    fn test_new_day() {
+        // A day just made by new-day, in a new year with an empty answers file
+        let mut days = registry::days();
+        days.push(Day::new::<crate::y2020::day01::DayOne>(2099, 1));
+        check_answers_files(&days, |year| match year {
+            2099 => Ok(String::new()),
+            _ => read_file(&answers_file(year)),
+        });
+    }
+}
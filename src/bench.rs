@@ -0,0 +1,240 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::input::Source;
+use crate::registry::{self, Day, Target};
+
+// The README table lives between these markers, so it can be regenerated in place
+const TABLE_START: &str = "<!-- bench:start -->";
+const TABLE_END: &str = "<!-- bench:end -->";
+
+/// Timings over several runs of one part
+pub struct Bench {
+    pub target: Target,
+    pub answer: String,
+    pub parse: Duration, // Median
+    pub solve: Duration, // Median
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Bench {
+    pub fn run(day: &Day, target: Target, input: &str, runs: usize) -> Result<Bench> {
+        if runs == 0 {
+            return Err(Error::usage("Benchmarks need at least one run"));
+        }
+        let results: Vec<registry::Run> = (0..runs)
+            .map(|_| day.run(input, target.part))
+            .collect::<Result<_>>()?;
+
+        let mut totals: Vec<Duration> = results.iter().map(|r| r.total()).collect();
+        totals.sort_unstable();
+
+        Ok(Bench {
+            target,
+            answer: results[0].answer.clone(),
+            parse: median(results.iter().map(|r| r.parse).collect()),
+            solve: median(results.iter().map(|r| r.solve).collect()),
+            min: totals[0],
+            median: median(totals.clone()),
+            max: totals[totals.len() - 1],
+        })
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort_unstable();
+    durations[durations.len() / 2]
+}
+
+// e.g. "bench --runs 3 --markdown README.md 15-17"
+// Returns the number of parts which failed
+pub fn main(days: &[Day], source: &Source, args: &[String]) -> Result<usize> {
+    let mut runs: usize = 5;
+    let mut markdown: Option<&str> = None;
+    let mut specs: Vec<&str> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| Error::usage("--runs needs a positive number"))?;
+            }
+            "--markdown" => {
+                markdown = Some(
+                    args.next()
+                        .ok_or_else(|| Error::usage("--markdown needs a file name"))?,
+                );
+            }
+            spec => specs.push(spec),
+        }
+    }
+    if specs.is_empty() {
+        specs.push("all");
+    }
+
+    let targets = registry::select_all(days, &specs)?;
+    source.check(&targets)?;
+
+    let mut benches: Vec<Bench> = Vec::new();
+    let mut failures = 0;
+    for target in targets {
+        let day = registry::find(days, target)?;
+        match source
+            .read(target.year, target.day)
+            .and_then(|input| Bench::run(day, target, &input, runs))
+        {
+            Ok(bench) => {
+                println!(
+                    "Day {}: -> {} (parse {:.2?}, solve {:.2?}; min {:.2?}, median {:.2?}, max {:.2?})",
+                    target, bench.answer, bench.parse, bench.solve, bench.min, bench.median, bench.max
+                );
+                benches.push(bench);
+            }
+            Err(e) => {
+                failures += 1;
+                eprintln!("Day {}: {}", target, e);
+            }
+        }
+    }
+
+    let table = markdown_table(&benches, runs);
+    println!();
+    print!("{}", table);
+
+    if let Some(filename) = markdown {
+        write_table(filename, &table)?;
+    }
+
+    Ok(failures)
+}
+
+pub fn markdown_table(benches: &[Bench], runs: usize) -> String {
+    let build = if cfg!(debug_assertions) {
+        "Debug"
+    } else {
+        "Release"
+    };
+
+    let mut table = format!("{} build, {} run(s) of each part.\n\n", build, runs);
+    table.push_str("| Day | Parse | Solve | Min | Median | Max\n");
+    table.push_str("| --- | --- | --- | --- | --- | ---\n");
+    for bench in benches {
+        table.push_str(&format!(
+            "| {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} | {:.2?}\n",
+            bench.target, bench.parse, bench.solve, bench.min, bench.median, bench.max
+        ));
+    }
+
+    table
+}
+
+// Replace the table between the markers.  A new file gets the markers too, but
+// an existing one without them is left alone rather than overwritten.
+fn write_table(filename: &str, table: &str) -> Result<()> {
+    let io_error = |source| Error::Io {
+        path: filename.to_string(),
+        source,
+    };
+
+    let contents = match fs::read_to_string(filename) {
+        Ok(existing) => match (existing.find(TABLE_START), existing.find(TABLE_END)) {
+            (Some(start), Some(end)) if start < end => format!(
+                "{}\n\n{}\n{}",
+                &existing[..start + TABLE_START.len()],
+                table,
+                &existing[end..]
+            ),
+            _ => {
+                return Err(Error::usage(format!(
+                    "{} has no '{}' and '{}' to put the table between",
+                    filename, TABLE_START, TABLE_END
+                )))
+            }
+        },
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            format!("{}\n\n{}\n{}\n", TABLE_START, table, TABLE_END)
+        }
+        Err(e) => return Err(io_error(e)),
+    };
+
+    fs::write(filename, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+    use std::{env, process};
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        assert!(median(vec![ms(3), ms(1), ms(2)]) == ms(2));
+        assert!(median(vec![ms(7)]) == ms(7));
+    }
+
+    #[test]
+    fn test_write_table() {
+        let path = env::temp_dir().join(format!("aoc-bench-{}.md", process::id()));
+        let filename = path.to_str().unwrap();
+        let _ = fs::remove_file(&path);
+
+        // A new file gets markers, so it can be rewritten
+        write_table(filename, "| old |\n").unwrap();
+        write_table(filename, "| new |\n").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents == format!("{}\n\n| new |\n\n{}\n", TABLE_START, TABLE_END));
+
+        // Without them, the file is left as it was
+        fs::write(&path, "# Notes\n").unwrap();
+        assert!(matches!(
+            write_table(filename, "| new |\n"),
+            Err(Error::Usage(_))
+        ));
+        assert!(fs::read_to_string(&path).unwrap() == "# Notes\n");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_table() {
+        let days = registry::days();
+        let target = Target {
+            year: 2020,
+            day: 1,
+            part: Part::One,
+        };
+        let bench = Bench::run(
+            registry::find(&days, target).unwrap(),
+            target,
+            "1721\n979\n366\n299\n675\n1456",
+            3,
+        )
+        .unwrap();
+        assert!(bench.answer == "514579");
+        assert!(bench.min <= bench.median && bench.median <= bench.max);
+
+        let table = markdown_table(&[bench], 3);
+        assert!(table.contains("| Day | Parse | Solve | Min | Median | Max\n"));
+        assert!(table.lines().last().unwrap().starts_with("| 2020/1a | "));
+
+        assert!(Bench::run(
+            registry::find(&days, target).unwrap(),
+            target,
+            "1721\nabc",
+            1
+        )
+        .is_err());
+        assert!(matches!(
+            Bench::run(registry::find(&days, target).unwrap(), target, "1721", 0),
+            Err(Error::Usage(_))
+        ));
+    }
+}
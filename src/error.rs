@@ -0,0 +1,161 @@
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong reading, parsing or solving a puzzle
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    // Line & column are 1-based, when we know them
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    // The input parsed, but it's a shape this solver can't handle
+    Unsupported(String),
+    NoSolution(String),
+    // Bad command line
+    Usage(String),
+    // Talking to the Advent of Code site (or whatever's standing in for it)
+    Fetch {
+        url: String,
+        message: String,
+    },
+}
+
+impl Error {
+    pub fn parse(message: impl Into<String>) -> Error {
+        Error::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Error {
+        Error::Unsupported(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Error {
+        Error::NoSolution(message.into())
+    }
+
+    pub fn usage(message: impl Into<String>) -> Error {
+        Error::Usage(message.into())
+    }
+
+    // Fill in the line number of a parse error, if it isn't already known
+    pub fn on_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse {
+                line: None,
+                column,
+                message,
+            } => Error::Parse {
+                line: Some(line),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    // Move a parse error down past the first `lines` lines, for something
+    // parsed out of the middle of the input
+    pub fn after_line(self, lines: usize) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => Error::Parse {
+                line: Some(line.unwrap_or(1) + lines),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    // Fill in the column of a parse error, if it isn't already known
+    pub fn at_column(self, column: usize) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column: None,
+                message,
+            } => Error::Parse {
+                line,
+                column: Some(column),
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Couldn't read {}: {}", path, source),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => match (line, column) {
+                (Some(line), Some(column)) => {
+                    write!(
+                        f,
+                        "Parse error at line {}, column {}: {}",
+                        line, column, message
+                    )
+                }
+                (Some(line), None) => write!(f, "Parse error at line {}: {}", line, message),
+                (None, Some(column)) => write!(f, "Parse error at column {}: {}", column, message),
+                (None, None) => write!(f, "Parse error: {}", message),
+            },
+            Error::Unsupported(message) => write!(f, "Unsupported input: {}", message),
+            Error::NoSolution(message) => write!(f, "No solution found: {}", message),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Fetch { url, message } => write!(f, "Couldn't fetch {}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let error = Error::parse("Bad thing").at_column(4).on_line(2);
+        assert!(error.to_string() == "Parse error at line 2, column 4: Bad thing");
+
+        // The innermost position wins
+        let error = Error::parse("Bad thing").on_line(2).on_line(7);
+        assert!(error.to_string() == "Parse error at line 2: Bad thing");
+
+        let error = Error::parse("Bad thing")
+            .at_column(3)
+            .on_line(2)
+            .after_line(10);
+        assert!(error.to_string() == "Parse error at line 12, column 3: Bad thing");
+
+        let error = Error::no_solution("Nope").on_line(2);
+        assert!(error.to_string() == "No solution found: Nope");
+    }
+}
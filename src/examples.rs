@@ -0,0 +1,196 @@
+//! The puzzles' worked examples and their answers, from each year's
+//! `data/<year>/examples.txt`.  One test runs them all, so adding an example
+//! is a line of data rather than another test function.
+
+use crate::error::{Error, Result};
+use crate::read_file;
+use crate::registry::{self, Day, Param, Target};
+
+/// The top of a new year's examples file
+pub const HEADER: &str = "\
+# Puzzle examples and their answers, checked by `cargo test`:
+#   <part> <input file> [<parameter>=<value> ...] -> <answer>
+# Input files are in this directory.  Parameters change settings that differ
+# between the examples and the real puzzle (see `Solution::with_param`).
+";
+
+pub fn examples_file(year: u32) -> String {
+    format!("data/{}/examples.txt", year)
+}
+
+/// One line of the manifest, e.g. "9a 9_example.txt preamble=5 -> 127"
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub target: Target,
+    pub input: String, // Path to the input file
+    pub params: Vec<Param>,
+    pub answer: String,
+    pub line: usize,
+}
+
+impl Example {
+    pub fn run(&self, days: &[Day]) -> Result<String> {
+        self.run_on(days, &read_file(&self.input)?)
+    }
+
+    // With some other input, in place of the file's
+    pub fn run_on(&self, days: &[Day], input: &str) -> Result<String> {
+        let day = registry::find(days, self.target)?;
+        Ok(day.run_with(input, self.target.part, &self.params)?.answer)
+    }
+}
+
+impl std::fmt::Display for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on {}", self.target, self.input)?;
+        for (name, value) in &self.params {
+            write!(f, " {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+// Comments and blank lines are skipped; input files are relative to the
+// year's data directory
+pub fn parse(manifest: &str, year: u32) -> Result<Vec<Example>> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (i, line) in manifest.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: String| Error::parse(message).on_line(i + 1);
+
+        let (run, answer) = line.split_once(" -> ").ok_or_else(|| {
+            error(format!(
+                "Expected '<part> <input> -> <answer>', found '{}'",
+                line
+            ))
+        })?;
+        let mut words = run.split_whitespace();
+        let target = words
+            .next()
+            .and_then(|part| Target::parse(part, year))
+            .ok_or_else(|| error(format!("Bad target in '{}'", line)))?;
+        let input = words
+            .next()
+            .ok_or_else(|| error(format!("Missing input file in '{}'", line)))?;
+        let params = words
+            .map(|param| {
+                param
+                    .split_once('=')
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .ok_or_else(|| error(format!("Expected 'name=value', found '{}'", param)))
+            })
+            .collect::<Result<_>>()?;
+
+        examples.push(Example {
+            target,
+            input: format!("data/{}/{}", year, input),
+            params,
+            answer: answer.trim().to_string(),
+            line: i + 1,
+        });
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_parse() {
+        let examples = parse(
+            "# Comment\n\n9a 9_example.txt preamble=5 -> 127\n2020/7b x.txt -> a,b",
+            2021,
+        )
+        .unwrap();
+        assert!(examples.len() == 2);
+        assert!(
+            examples[0]
+                == Example {
+                    target: Target {
+                        year: 2021,
+                        day: 9,
+                        part: Part::One
+                    },
+                    input: "data/2021/9_example.txt".to_string(),
+                    params: vec![("preamble".to_string(), "5".to_string())],
+                    answer: "127".to_string(),
+                    line: 3,
+                }
+        );
+        assert!(examples[1].target.year == 2020 && examples[1].answer == "a,b");
+        assert!(examples[0].to_string() == "2021/9a on data/2021/9_example.txt preamble=5");
+
+        let error = parse("7a 7_example.txt = 4", 2020).err().unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(1), .. }));
+        assert!(parse("7a -> 4", 2020).is_err());
+        assert!(parse("7a 7_example.txt moves -> 4", 2020).is_err());
+
+        // new-day starts a year's file with the same header
+        assert!(read_file(&examples_file(2020)).unwrap().starts_with(HEADER));
+    }
+
+    #[test]
+    fn test_examples() {
+        // Every year's examples give the answers the puzzles say they should
+        let days = registry::days();
+        let mut years: Vec<u32> = days.iter().map(|d| d.year).collect();
+        years.dedup();
+
+        let mut failures: Vec<String> = Vec::new();
+        for year in years {
+            let examples = parse(&read_file(&examples_file(year)).unwrap(), year).unwrap();
+            for example in examples {
+                match example.run(&days) {
+                    Ok(answer) if answer == example.answer => {}
+                    Ok(answer) => failures.push(format!(
+                        "line {}: {} gave {}, expected {}",
+                        example.line, example, answer, example.answer
+                    )),
+                    Err(e) => failures.push(format!("line {}: {}: {}", example.line, example, e)),
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        // Windows line endings, a byte order mark and blank lines at the end
+        // don't change any answers
+        let days = registry::days();
+        let examples = parse(&read_file(&examples_file(2020)).unwrap(), 2020).unwrap();
+        let mut failures: Vec<String> = Vec::new();
+        for example in examples {
+            let input = read_file(&example.input).unwrap();
+            let input = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"));
+            match example.run_on(&days, &input) {
+                Ok(answer) if answer == example.answer => {}
+                other => failures.push(format!(
+                    "line {}: {} gave {:?}",
+                    example.line, example, other
+                )),
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_bad_param() {
+        let days = registry::days();
+        let example = &parse("9a 9_example.txt size=5 -> 127", 2020).unwrap()[0];
+        assert!(matches!(example.run(&days), Err(Error::Usage(_))));
+        let example = &parse("9a 9_example.txt preamble=x -> 127", 2020).unwrap()[0];
+        assert!(matches!(example.run(&days), Err(Error::Parse { .. })));
+        let example = &parse("1a 1a_example.txt preamble=5 -> 514579", 2020).unwrap()[0];
+        assert!(matches!(example.run(&days), Err(Error::Usage(_))));
+    }
+}
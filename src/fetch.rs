@@ -0,0 +1,202 @@
+//! Puzzle inputs live in a cache directory of `<year>/<day>.txt` files.  When
+//! one's missing, and there's a session token to log in with, it's downloaded
+//! from the site and saved there for next time.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+use crate::http::{self, Http, USER_AGENT};
+use crate::read_file;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The site's URL and the session to log in with, from $AOC_BASE_URL
+/// (optional) and $AOC_SESSION
+pub fn site_from_env() -> Option<(String, String)> {
+    let session = env::var("AOC_SESSION")
+        .ok()
+        .filter(|s| !s.trim().is_empty())?;
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+    Some((base_url, session))
+}
+
+/// Downloads inputs, logged in with the `session` cookie from a browser
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    http: Box<dyn Http>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, http: Box<dyn Http>) -> Fetcher {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            http,
+        }
+    }
+
+    /// Set up from the environment - or None if there's no session, so
+    /// nothing gets fetched
+    pub fn from_env() -> Option<Fetcher> {
+        let (base_url, session) = site_from_env()?;
+        Some(Fetcher::new(&base_url, &session, http::backend(&base_url)))
+    }
+
+    pub fn url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn fetch(&self, year: u32, day: u32) -> Result<String> {
+        let url = self.url(year, day);
+        verbose!("Fetching {}", url);
+        let cookie = format!("session={}", self.session);
+        let response = self
+            .http
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+
+        let reason = response.body.lines().next().unwrap_or("").trim();
+        let message = match response.status {
+            200 => return Ok(response.body),
+            404 => "HTTP 404 - the puzzle isn't out yet, or doesn't exist".to_string(),
+            // What the site says to a missing or expired session
+            400 | 401 | 403 | 500 => format!(
+                "HTTP {} - check the token in AOC_SESSION ({})",
+                response.status, reason
+            ),
+            status => format!("HTTP {} ({})", status, reason),
+        };
+        Err(Error::Fetch { url, message })
+    }
+}
+
+/// Inputs in `<dir>/<year>/<day>.txt`, fetched when they're missing if
+/// there's a fetcher
+pub struct Cache {
+    dir: String,
+    fetcher: Option<Fetcher>,
+    // So parts run in parallel don't fetch the same input twice
+    fetching: Mutex<()>,
+}
+
+impl Cache {
+    pub fn new(dir: &str, fetcher: Option<Fetcher>) -> Cache {
+        Cache {
+            dir: dir.to_string(),
+            fetcher,
+            fetching: Mutex::new(()),
+        }
+    }
+
+    pub fn dir(&self) -> &str {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/{}.txt", self.dir, year, day)
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String> {
+        let path = self.path(year, day);
+        let fetcher = match &self.fetcher {
+            Some(fetcher) if !Path::new(&path).exists() => fetcher,
+            _ => return read_file(&path),
+        };
+
+        let _fetching = self.fetching.lock().unwrap();
+        if Path::new(&path).exists() {
+            // Someone else got it while we waited
+            return read_file(&path);
+        }
+
+        let input = fetcher.fetch(year, day)?;
+        let io = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+        fs::create_dir_all(format!("{}/{}", self.dir, year)).map_err(io)?;
+        fs::write(&path, &input).map_err(io)?;
+        verbose!("Saved {}", path);
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+    use std::net::TcpListener;
+    use std::process;
+
+    // A fresh, empty cache directory for each test
+    fn scratch(name: &str) -> String {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.to_str().unwrap().to_string()
+    }
+
+    fn site() -> stub::Server {
+        stub::Server::start(|request| {
+            if !request.contains("\r\nCookie: session=abc\r\n") {
+                return (400, "Please log in to get your puzzle input.\n".to_string());
+            }
+            match request.lines().next() {
+                Some("GET /2020/day/7/input HTTP/1.0") => (200, "1\n2\n3\n".to_string()),
+                _ => (404, "404 Not Found\n".to_string()),
+            }
+        })
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let server = site();
+        let dir = scratch("cache");
+        let cache = Cache::new(
+            &dir,
+            Some(Fetcher::new(&server.url, "abc\n", Box::new(http::Plain))),
+        );
+
+        assert!(cache.read(2020, 7).unwrap() == "1\n2\n3\n");
+        assert!(read_file(&cache.path(2020, 7)).unwrap() == "1\n2\n3\n");
+        // The second time comes from the cache
+        assert!(cache.read(2020, 7).unwrap() == "1\n2\n3\n");
+
+        let requests = server.requests();
+        assert!(requests.len() == 1);
+        assert!(requests[0].contains(&format!("\r\nUser-Agent: {}\r\n", USER_AGENT)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = site();
+        let dir = scratch("errors");
+        let fetcher = |session| Fetcher::new(&server.url, session, Box::new(http::Plain));
+
+        let cache = Cache::new(&dir, Some(fetcher("abc")));
+        let error = cache.read(2020, 8).err().unwrap();
+        assert!(matches!(error, Error::Fetch { ref message, .. } if message.contains("404")));
+        assert!(!Path::new(&cache.path(2020, 8)).exists());
+
+        let cache = Cache::new(&dir, Some(fetcher("expired")));
+        let error = cache.read(2020, 7).err().unwrap();
+        assert!(error.to_string().contains("check the token in AOC_SESSION"));
+        assert!(!Path::new(&cache.path(2020, 7)).exists());
+
+        // Without a fetcher, a missing input is just a missing file
+        let cache = Cache::new(&dir, None);
+        assert!(matches!(cache.read(2020, 7), Err(Error::Io { .. })));
+
+        // Nothing listening
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let cache = Cache::new(&dir, Some(Fetcher::new(&url, "abc", Box::new(http::Plain))));
+        assert!(matches!(cache.read(2020, 7), Err(Error::Fetch { .. })));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
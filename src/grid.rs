@@ -0,0 +1,488 @@
+//! 2D grids, for the days whose input is a map of characters.  `Grid` stores
+//! every cell of a rectangle; `Sparse` just the set of "on" points, for
+//! cellular automata that grow without bound (and in any number of
+//! dimensions).
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use crate::error::{Error, Result};
+
+/// (x, y), with x going right and y going down from the top-left corner
+pub type Point = (isize, isize);
+
+/// Up, right, down & left
+pub const ORTHOGONAL: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// All eight squares around a point, diagonals included
+pub const ADJACENT: [Point; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The six neighbours of a hexagon in axial coordinates: E-W is the x axis
+/// and NE-SW the y axis, so E is +x and NE is +y.  In order E, SE, SW, W, NW
+/// and NE.
+pub const HEX: [Point; 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+/// Which edges of a grid join up with the opposite edge
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wrap {
+    None,
+    // Left to right, like the toboggan map
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+/// A rectangle of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrap: Wrap,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+            wrap: Wrap::None,
+        }
+    }
+
+    /// A quarter turn anti-clockwise: the right-hand column becomes the top
+    /// row
+    pub fn rotate(&mut self) {
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for x in (0..self.width).rev() {
+            cells.extend(self.column(x as isize).cloned());
+        }
+        self.cells = cells;
+        std::mem::swap(&mut self.width, &mut self.height);
+    }
+
+    /// Each orientation the grid can be turned or flipped to - the first is
+    /// the grid as it is
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut grid = self.clone();
+        let mut orientations: Vec<Grid<T>> = Vec::new();
+        for flip in 0..2 {
+            if flip == 1 {
+                grid.flip();
+            }
+            for _ in 0..4 {
+                orientations.push(grid.clone());
+                grid.rotate();
+            }
+        }
+        orientations
+    }
+}
+
+impl<T> Grid<T> {
+    /// One cell per character, with `cell` saying what each character is (or
+    /// `None` if it shouldn't be there).  Every line must be the same length,
+    /// though lines can end with "\r\n" and there can be blank lines at the end.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let lines: Vec<&str> = input.trim_end_matches(&['\r', '\n'][..]).lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(Error::parse("Empty grid").on_line(1));
+        }
+
+        let mut cells: Vec<T> = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let error = |e: Error| e.on_line(y + 1);
+            let mut found = 0;
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    error(Error::parse(format!("Unexpected char: {:?}", c)).at_column(x + 1))
+                })?;
+                cells.push(value);
+                found += 1;
+            }
+            if found != width {
+                return Err(error(Error::parse(format!(
+                    "Expected {} squares, found {}",
+                    width, found
+                ))));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+            wrap: Wrap::None,
+        })
+    }
+
+    /// Join up the edges, so points past them are taken from the other side
+    pub fn with_wrap(self, wrap: Wrap) -> Grid<T> {
+        Grid { wrap, ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Where a point is in `cells`, after wrapping - or None if it's off the
+    // grid
+    fn index(&self, (x, y): Point) -> Option<usize> {
+        let wrap = |n: isize, size: usize, wraps: bool| {
+            if wraps && size > 0 {
+                Some(n.rem_euclid(size as isize) as usize)
+            } else if 0 <= n && (n as usize) < size {
+                Some(n as usize)
+            } else {
+                None
+            }
+        };
+        let x = wrap(
+            x,
+            self.width,
+            matches!(self.wrap, Wrap::Horizontal | Wrap::Both),
+        )?;
+        let y = wrap(
+            y,
+            self.height,
+            matches!(self.wrap, Wrap::Vertical | Wrap::Both),
+        )?;
+        Some(y * self.width + x)
+    }
+
+    fn point(&self, index: usize) -> Point {
+        ((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    /// Whether the point is on the grid, without any wrapping
+    pub fn contains(&self, (x, y): Point) -> bool {
+        0 <= x && (x as usize) < self.width && 0 <= y && (y as usize) < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (self.point(i), cell))
+    }
+
+    pub fn row(&self, y: isize) -> impl Iterator<Item = &T> {
+        (0..self.width as isize).filter_map(move |x| self.get((x, y)))
+    }
+
+    pub fn column(&self, x: isize) -> impl Iterator<Item = &T> {
+        (0..self.height as isize).filter_map(move |y| self.get((x, y)))
+    }
+
+    /// The cells at each offset from a point (e.g. `ADJACENT`) that are on
+    /// the grid, or wrap onto it.  Points are given as they are after
+    /// wrapping.
+    pub fn neighbours<'a>(
+        &'a self,
+        (x, y): Point,
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            self.index((x + dx, y + dy))
+                .map(|i| (self.point(i), &self.cells[i]))
+        })
+    }
+
+    /// The cells seen looking from a point (not including it) in steps of
+    /// `step`, as far as the edge.  Rays don't wrap, even when the grid does,
+    /// or they'd never end.
+    pub fn ray(&self, (x, y): Point, (dx, dy): Point) -> impl Iterator<Item = (Point, &T)> {
+        (1..)
+            .map(move |n| (x + n * dx, y + n * dy))
+            .take_while(move |&point| self.contains(point))
+            .map(move |point| (point, &self.cells[self.index(point).unwrap()]))
+    }
+
+    /// A grid the same shape, with each cell worked out from this one
+    pub fn map<U>(&self, f: impl Fn(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(point, cell)| f(point, cell)).collect(),
+            wrap: self.wrap,
+        }
+    }
+
+    /// Turn it over top to bottom
+    pub fn flip(&mut self) {
+        let width = self.width;
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(self.height);
+        let mut cells = std::mem::take(&mut self.cells).into_iter();
+        for _ in 0..self.height {
+            rows.push(cells.by_ref().take(width).collect());
+        }
+        self.cells = rows.into_iter().rev().flatten().collect();
+    }
+
+    /// One line per row, with `cell` drawing each cell
+    pub fn render(&self, cell: impl Fn(Point, &T) -> char) -> String {
+        let mut lines: Vec<String> = Vec::with_capacity(self.height);
+        for y in 0..self.height as isize {
+            lines.push(
+                (0..self.width as isize)
+                    .map(|x| cell((x, y), self.get((x, y)).unwrap()))
+                    .collect(),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+// On & off as '#' & '.', as the puzzles draw them
+impl fmt::Display for Grid<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|_, &on| if on { '#' } else { '.' }))
+    }
+}
+
+/// The points that are "on" in an unbounded space, e.g. for the Game of
+/// Life-like puzzles.  Points can be anything hashable - `Point`, or a tuple
+/// with more dimensions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sparse<P: Eq + Hash> {
+    on: HashSet<P>,
+}
+
+impl<P: Copy + Eq + Hash> Sparse<P> {
+    pub fn new() -> Sparse<P> {
+        Sparse { on: HashSet::new() }
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.on.contains(&point)
+    }
+
+    pub fn insert(&mut self, point: P) {
+        self.on.insert(point);
+    }
+
+    /// Switch a point on if it's off, or off if it's on
+    pub fn toggle(&mut self, point: P) {
+        if !self.on.remove(&point) {
+            self.on.insert(point);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.on.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.on.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &P> {
+        self.on.iter()
+    }
+
+    /// One generation of a cellular automaton: `rule(on, n)` says whether a
+    /// point is on next time, given whether it's on now and how many of its
+    /// `neighbours` are.  Only points next to one that's on are considered,
+    /// so `rule(false, 0)` must be false.
+    pub fn step<N: IntoIterator<Item = P>>(
+        &self,
+        neighbours: impl Fn(P) -> N,
+        rule: impl Fn(bool, usize) -> bool,
+    ) -> Sparse<P> {
+        let mut counts: HashMap<P, usize> = self.on.iter().map(|&point| (point, 0)).collect();
+        for &point in &self.on {
+            for neighbour in neighbours(point) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .filter(|&(point, count)| rule(self.contains(point), count))
+            .map(|(point, _)| point)
+            .collect()
+    }
+}
+
+impl Sparse<Point> {
+    /// The smallest grid holding every point that's on, and where its
+    /// top-left corner is
+    pub fn to_grid(&self) -> (Grid<bool>, Point) {
+        let xs = || self.on.iter().map(|p| p.0);
+        let ys = || self.on.iter().map(|p| p.1);
+        let (min_x, min_y) = (xs().min().unwrap_or(0), ys().min().unwrap_or(0));
+        let width = xs().max().map_or(0, |max| (max - min_x + 1) as usize);
+        let height = ys().max().map_or(0, |max| (max - min_y + 1) as usize);
+
+        let mut grid = Grid::new(width, height, false);
+        for &(x, y) in &self.on {
+            *grid.get_mut((x - min_x, y - min_y)).unwrap() = true;
+        }
+        (grid, (min_x, min_y))
+    }
+}
+
+impl<P: Copy + Eq + Hash> Default for Sparse<P> {
+    fn default() -> Sparse<P> {
+        Sparse::new()
+    }
+}
+
+impl<P: Copy + Eq + Hash> FromIterator<P> for Sparse<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(points: I) -> Sparse<P> {
+        Sparse {
+            on: points.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#..\n.#.\n", tree).unwrap();
+        assert!(grid.width() == 3 && grid.height() == 2);
+        assert!(grid.get((0, 0)) == Some(&true));
+        assert!(grid.get((1, 1)) == Some(&true));
+        assert!(grid.get((2, 1)) == Some(&false));
+        assert!(grid.get((3, 0)).is_none());
+        assert!(grid.to_string() == "#..\n.#.");
+        let crlf = Grid::parse("#..\r\n.#.\r\n\r\n", tree).unwrap();
+        assert!(crlf.to_string() == grid.to_string());
+
+        let error = Grid::parse("..#\n.x.", tree).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 2: Unexpected char: 'x'");
+        let error = Grid::parse("..#\n..", tree).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Expected 3 squares, found 2");
+        assert!(Grid::parse("", tree).is_err());
+    }
+
+    #[test]
+    fn test_wrap() {
+        let grid = Grid::parse("#..\n.#.", tree).unwrap();
+        assert!(grid.get((4, 1)).is_none());
+
+        let grid = grid.with_wrap(Wrap::Horizontal);
+        assert!(grid.get((4, 1)) == Some(&true));
+        assert!(grid.get((-3, 0)) == Some(&true));
+        assert!(grid.get((0, 2)).is_none());
+
+        let grid = grid.with_wrap(Wrap::Both);
+        assert!(grid.get((3, 2)) == Some(&true));
+        let neighbours: Vec<Point> = grid
+            .neighbours((0, 0), &ORTHOGONAL)
+            .map(|(p, _)| p)
+            .collect();
+        assert!(neighbours == vec![(0, 1), (1, 0), (0, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("#..\n.#.\n..#", tree).unwrap();
+        assert!(grid.neighbours((0, 0), &ADJACENT).count() == 3);
+        assert!(grid.neighbours((1, 1), &ADJACENT).count() == 8);
+        assert!(
+            grid.neighbours((1, 1), &ADJACENT)
+                .filter(|(_, &on)| on)
+                .count()
+                == 2
+        );
+        assert!(
+            grid.neighbours((1, 1), &ORTHOGONAL)
+                .filter(|(_, &on)| on)
+                .count()
+                == 0
+        );
+
+        let ray: Vec<(Point, &bool)> = grid.ray((0, 0), (1, 1)).collect();
+        assert!(ray == vec![((1, 1), &true), ((2, 2), &true)]);
+        assert!(grid.ray((0, 0), (-1, 0)).next().is_none());
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut grid = Grid::parse("#..\n.##", tree).unwrap();
+        grid.rotate();
+        assert!(grid.to_string() == ".#\n.#\n#.");
+        grid.flip();
+        assert!(grid.to_string() == "#.\n.#\n.#");
+
+        let grid = Grid::parse("#.\n..", tree).unwrap();
+        let orientations = grid.orientations();
+        assert!(orientations.len() == 8);
+        assert!(orientations[0] == grid);
+        assert!(
+            orientations
+                .iter()
+                .filter(|g| g.get((1, 1)) == Some(&true))
+                .count()
+                == 2
+        );
+
+        let render = grid.map(|(x, y), &on| (x + y, on)).render(|_, &(n, on)| {
+            if on {
+                '#'
+            } else {
+                std::char::from_digit(n as u32, 10).unwrap()
+            }
+        });
+        assert!(render == "#1\n12");
+    }
+
+    #[test]
+    fn test_sparse() {
+        // A blinker, in the Game of Life
+        let mut blinker: Sparse<Point> = vec![(0, 1), (1, 1), (2, 1)].into_iter().collect();
+        let life = |on: bool, n: usize| n == 3 || (on && n == 2);
+        let neighbours = |(x, y): Point| ADJACENT.iter().map(move |(dx, dy)| (x + dx, y + dy));
+
+        blinker = blinker.step(neighbours, life);
+        assert!(blinker.len() == 3 && blinker.contains((1, 0)) && blinker.contains((1, 2)));
+        let (grid, corner) = blinker.to_grid();
+        assert!(grid.to_string() == "#\n#\n#" && corner == (1, 0));
+        blinker = blinker.step(neighbours, life);
+        assert!(blinker.to_grid().0.to_string() == "###");
+
+        blinker.toggle((1, 1));
+        assert!(blinker.len() == 2);
+        blinker.toggle((1, 1));
+        assert!(blinker.len() == 3);
+        assert!(Sparse::<Point>::new().to_grid().0.width() == 0);
+    }
+}
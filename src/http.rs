@@ -0,0 +1,303 @@
+//! Just enough HTTP to talk to the Advent of Code site.  The backend is a trait
+//! so tests can point everything at a stub server on localhost.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub const USER_AGENT: &str = "github.com/sgdavies/aoc2020";
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Http: Send + Sync {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response>;
+
+    // A form post, e.g. "level=1&answer=123"
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> Result<Response>;
+}
+
+/// The backend for a URL: plain HTTP is done in-process, HTTPS by `curl`
+pub fn backend(url: &str) -> Box<dyn Http> {
+    if url.starts_with("https://") {
+        Box::new(Curl)
+    } else {
+        Box::new(Plain)
+    }
+}
+
+fn error(url: &str, message: impl Into<String>) -> Error {
+    Error::Fetch {
+        url: url.to_string(),
+        message: message.into(),
+    }
+}
+
+/// HTTP/1.0 over a `TcpStream` - no TLS, so only for http:// URLs
+pub struct Plain;
+
+impl Http for Plain {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+        self.send("GET", url, headers, None)
+    }
+
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> Result<Response> {
+        self.send("POST", url, headers, Some(form))
+    }
+}
+
+impl Plain {
+    fn send(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: Option<&str>,
+    ) -> Result<Response> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| error(url, "only http:// URLs are supported"))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{}:80", host)
+        };
+
+        let mut request = format!("{} {} HTTP/1.0\r\nHost: {}\r\n", method, path, host);
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        if let Some(form) = form {
+            request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+            request.push_str(&format!("Content-Length: {}\r\n\r\n{}", form.len(), form));
+        } else {
+            request.push_str("\r\n");
+        }
+
+        let io = |e: std::io::Error| error(url, e.to_string());
+        let mut stream = TcpStream::connect(&address).map_err(io)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.write_all(request.as_bytes()).map_err(io)?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(io)?;
+
+        parse_response(&response).ok_or_else(|| error(url, "malformed response"))
+    }
+}
+
+// e.g. "HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\nbody"
+fn parse_response(response: &str) -> Option<Response> {
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let status = head
+        .lines()
+        .next()?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()?;
+    Some(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Shells out to `curl`, for HTTPS.  Headers go in on stdin so the session
+/// token doesn't show up in `ps`.
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+        self.send(url, headers, None)
+    }
+
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> Result<Response> {
+        self.send(url, headers, Some(form))
+    }
+}
+
+impl Curl {
+    fn send(&self, url: &str, headers: &[(&str, &str)], form: Option<&str>) -> Result<Response> {
+        let mut command = Command::new("curl");
+        if let Some(form) = form {
+            // Sets the content type too
+            command.args(["--data-binary", form]);
+        }
+        let mut child = command
+            .args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+            .args(["--write-out", "\n%{http_code}", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| error(url, format!("couldn't run curl: {}", e)))?;
+
+        let headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}: {}\n", name, value))
+            .collect();
+        let io = |e: std::io::Error| error(url, e.to_string());
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(headers.as_bytes())
+            .map_err(io)?;
+        let output = child.wait_with_output().map_err(io)?;
+        if !output.status.success() {
+            return Err(error(
+                url,
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output
+            .rsplit_once('\n')
+            .ok_or_else(|| error(url, "malformed response"))?;
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| error(url, "malformed response"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// A one-thread HTTP server on localhost for tests.  It answers each request
+/// with whatever `respond` returns for the request's text, and keeps every
+/// request so tests can check what was sent.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Server {
+        pub fn start(respond: impl Fn(&str) -> (u16, String) + Send + 'static) -> Server {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&mut stream);
+                    let (status, body) = respond(&request);
+                    seen.lock().unwrap().push(request);
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.0 {} Stub\r\nContent-Length: {}\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                }
+            });
+
+            Server { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    // Headers, and a body if there's a Content-Length
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request: Vec<u8> = Vec::new();
+        let mut byte = [0; 1];
+        while !request.ends_with(b"\r\n\r\n") && stream.read(&mut byte).unwrap() == 1 {
+            request.push(byte[0]);
+        }
+
+        let head = String::from_utf8(request).unwrap();
+        let length = head
+            .lines()
+            .find_map(|l| l.strip_prefix("Content-Length: "))
+            .map_or(0, |n| n.trim().parse().unwrap());
+        let mut body = vec![0; length];
+        stream.read_exact(&mut body).unwrap();
+        head + &String::from_utf8(body).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response("HTTP/1.1 404 Not Found\r\nA: b\r\n\r\nNope\n").unwrap();
+        assert!(response.status == 404);
+        assert!(response.body == "Nope\n");
+
+        assert!(parse_response("HTTP/1.1 200 OK\r\n").is_none());
+        assert!(parse_response("garbage\r\n\r\n").is_none());
+    }
+
+    #[test]
+    fn test_plain() {
+        let server = stub::Server::start(|request| match request.lines().next() {
+            Some("GET /hello HTTP/1.0") => (200, "Hi".to_string()),
+            _ => (404, String::new()),
+        });
+
+        let response = Plain
+            .get(&format!("{}/hello", server.url), &[("X-Test", "yes")])
+            .unwrap();
+        assert!(response.status == 200 && response.body == "Hi");
+        let response = Plain.get(&format!("{}/other", server.url), &[]).unwrap();
+        assert!(response.status == 404);
+        Plain
+            .post(&format!("{}/form", server.url), &[], "a=1&b=2")
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests.len() == 3);
+        assert!(requests[0].contains("\r\nX-Test: yes\r\n"));
+        assert!(requests[2].starts_with("POST /form HTTP/1.0\r\n"));
+        assert!(requests[2].ends_with("\r\n\r\na=1&b=2"));
+
+        assert!(matches!(
+            Plain.get("ftp://example.com", &[]),
+            Err(Error::Fetch { .. })
+        ));
+    }
+
+    // Curl does plain HTTP too, so it can be checked against the stub
+    #[test]
+    #[ignore = "needs curl installed - run with `cargo test -- --ignored`"]
+    fn test_curl() {
+        let server = stub::Server::start(|_| (403, "Go away\n".to_string()));
+
+        let response = Curl
+            .get(&format!("{}/x", server.url), &[("Cookie", "session=abc")])
+            .unwrap();
+        assert!(response.status == 403 && response.body == "Go away\n");
+        Curl.post(&format!("{}/y", server.url), &[], "a=1").unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("POST /y HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\na=1"));
+    }
+}
@@ -0,0 +1,127 @@
+use std::io::{self, Read};
+
+use crate::error::{Error, Result};
+use crate::fetch::{Cache, Fetcher};
+use crate::read_file;
+use crate::registry::Target;
+
+const INPUT_DIR: &str = "data";
+
+/// Where to find puzzle inputs
+pub enum Source {
+    // A directory of "<year>/<day>.txt" files, fetching any that are missing
+    // if we can
+    Dir(Cache),
+    // One file, for a single day
+    File(String),
+    // Already read from stdin, for a single day
+    Text(String),
+}
+
+impl Source {
+    /// Pull the input options out of the command line, leaving the rest:
+    ///   --input FILE    - use this file (only for one day at a time)
+    ///   --input -       - read from stdin (only for one day at a time)
+    ///   --input-dir DIR - look for DIR/<year>/<day>.txt instead of data/<year>/<day>.txt
+    /// Missing inputs in the directory are fetched if $AOC_SESSION is set.
+    pub fn from_args(args: &[String]) -> Result<(Source, Vec<String>)> {
+        let mut source = Source::Dir(Cache::new(INPUT_DIR, Fetcher::from_env()));
+        let mut rest: Vec<String> = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let filename = args
+                        .next()
+                        .ok_or_else(|| Error::usage("--input needs a file name"))?;
+                    source = match filename.as_str() {
+                        "-" => {
+                            let mut input = String::new();
+                            io::stdin()
+                                .read_to_string(&mut input)
+                                .map_err(|source| Error::Io {
+                                    path: "stdin".to_string(),
+                                    source,
+                                })?;
+                            Source::Text(input)
+                        }
+                        filename => Source::File(filename.to_string()),
+                    }
+                }
+                "--input-dir" => {
+                    let dir = args
+                        .next()
+                        .ok_or_else(|| Error::usage("--input-dir needs a directory name"))?;
+                    source = Source::Dir(Cache::new(dir, Fetcher::from_env()));
+                }
+                _ => rest.push(arg.to_string()),
+            }
+        }
+
+        Ok((source, rest))
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String> {
+        match self {
+            Source::Dir(cache) => cache.read(year, day),
+            Source::File(filename) => read_file(filename),
+            Source::Text(input) => Ok(input.clone()),
+        }
+    }
+
+    // A single input can't be shared between different days
+    pub fn check(&self, targets: &[Target]) -> Result<()> {
+        match self {
+            Source::File(_) | Source::Text(_)
+                if targets
+                    .iter()
+                    .any(|t| (t.year, t.day) != (targets[0].year, targets[0].day)) =>
+            {
+                Err(Error::usage(
+                    "--input can only be used when running a single day",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        let (source, rest) = Source::from_args(&args(&["7b", "--input-dir", "mine", "8"])).unwrap();
+        assert!(matches!(source, Source::Dir(ref cache) if cache.dir() == "mine"));
+        assert!(rest == ["7b", "8"]);
+
+        let (source, rest) =
+            Source::from_args(&args(&["--input", "data/2020/7_example.txt", "7"])).unwrap();
+        assert!(matches!(source, Source::File(ref f) if f == "data/2020/7_example.txt"));
+        assert!(rest == ["7"]);
+
+        let (source, _) = Source::from_args(&args(&["7"])).unwrap();
+        assert!(matches!(source, Source::Dir(ref cache) if cache.dir() == "data"));
+
+        assert!(Source::from_args(&args(&["7", "--input"])).is_err());
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = Source::Dir(Cache::new("data", None));
+        assert!(dir.read(2020, 23).unwrap() == "685974213");
+        assert!(dir.read(2019, 23).is_err());
+        let file = Source::File("data/2020/23.txt".to_string());
+        assert!(file.read(2015, 1).unwrap() == "685974213");
+        assert!(Source::Text("abc".to_string()).read(2020, 1).unwrap() == "abc");
+        assert!(Source::Dir(Cache::new("nowhere", None))
+            .read(2020, 1)
+            .is_err());
+    }
+}
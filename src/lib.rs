@@ -0,0 +1,55 @@
+//! Advent of Code solvers, usable as a library.
+//!
+//! Each year is a `yYYYY` module, whose `dayNN` modules each have a struct
+//! implementing [`solution::Solution`], plus the model its input parses into.
+//! [`registry`] lists every day of every year, so they can be run without
+//! naming the types.
+
+use std::fs;
+use std::str::FromStr;
+
+#[macro_use]
+pub mod log;
+
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod output;
+pub mod parallel;
+pub mod parse;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod y2020;
+
+use crate::error::{Error, Result};
+
+extern crate lazy_static;
+extern crate regex;
+
+/// Utils
+// Read a whole file into a string
+pub fn read_file(filename: &str) -> Result<String> {
+    fs::read_to_string(filename).map_err(|source| Error::Io {
+        path: filename.to_string(),
+        source,
+    })
+}
+
+pub fn parse_num<T: FromStr>(s: &str) -> Result<T> {
+    s.parse::<T>()
+        .map_err(|_| Error::parse(format!("Expected a number, found '{}'", s)))
+}
+
+// A parameter that's on or off, e.g. "all=true"
+pub fn parse_bool(name: &str, value: &str) -> Result<bool> {
+    value
+        .parse()
+        .map_err(|_| Error::usage(format!("{} must be true or false, not '{}'", name, value)))
+}
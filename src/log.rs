@@ -0,0 +1,24 @@
+//! Solver diagnostics.  They're off by default so stdout only carries answers;
+//! `--verbose` sends them to stderr.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Like `eprintln!`, but only when verbose output is turned on
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,150 @@
+use std::fmt::Write;
+
+use crate::error::{Error, Result};
+use crate::registry::{Run, Target};
+
+/// How the runner prints its results
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    // "Day 7b: -> 220149" lines, and a summary table
+    Text,
+    // One JSON object per line, per part
+    Json,
+}
+
+impl Format {
+    /// Pull "--format text|json" out of the command line, leaving the rest
+    pub fn from_args(args: &[String]) -> Result<(Format, Vec<String>)> {
+        let mut format = Format::Text;
+        let mut rest: Vec<String> = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    format = match args.next().map(String::as_str) {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        _ => return Err(Error::usage("--format needs 'text' or 'json'")),
+                    }
+                }
+                _ => rest.push(arg.to_string()),
+            }
+        }
+
+        Ok((format, rest))
+    }
+}
+
+// e.g. {"year":2020,"day":7,"part":"b","answer":"220149","duration_ms":1.234,"error":null}
+// Exactly one of answer and error is null.
+pub fn json_line(target: Target, result: &Result<Run>) -> String {
+    let (answer, duration, error) = match result {
+        Ok(run) => (
+            json_string(&run.answer),
+            format!("{:.3}", run.total().as_secs_f64() * 1000.0),
+            "null".to_string(),
+        ),
+        Err(e) => (
+            "null".to_string(),
+            "null".to_string(),
+            json_string(&e.to_string()),
+        ),
+    };
+
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":\"{}\",\"answer\":{},\"duration_ms\":{},\"error\":{}}}",
+        target.year,
+        target.day,
+        target.part.suffix(),
+        answer,
+        duration,
+        error
+    )
+}
+
+/// A JSON string literal, quotes and all
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A CSV field, quoted if it has to be (RFC 4180)
+pub fn csv_field(s: &str) -> String {
+    match s.contains(&[',', '"', '\n', '\r'][..]) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn test_json_string() {
+        assert!(json_string("abc,def") == "\"abc,def\"");
+        assert!(json_string("say \"hi\"\n") == "\"say \\\"hi\\\"\\n\"");
+        assert!(json_string("a\\b\u{1}") == "\"a\\\\b\\u0001\"");
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert!(csv_field("abc") == "abc");
+        assert!(csv_field("a,b") == "\"a,b\"");
+        assert!(csv_field("say \"hi\"") == "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_json_line() {
+        let target = Target {
+            year: 2020,
+            day: 7,
+            part: Part::Two,
+        };
+        let run = Run {
+            answer: "220149".to_string(),
+            parse: Duration::from_micros(1000),
+            solve: Duration::from_micros(234),
+            extras: Vec::new(),
+        };
+        assert!(
+            json_line(target, &Ok(run))
+                == "{\"year\":2020,\"day\":7,\"part\":\"b\",\"answer\":\"220149\",\"duration_ms\":1.234,\"error\":null}"
+        );
+
+        let error = Err(Error::parse("Bad thing").on_line(3));
+        assert!(
+            json_line(target, &error)
+                == "{\"year\":2020,\"day\":7,\"part\":\"b\",\"answer\":null,\"duration_ms\":null,\"error\":\"Parse error at line 3: Bad thing\"}"
+        );
+    }
+
+    #[test]
+    fn test_from_args() {
+        let args: Vec<String> = ["7", "--format", "json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (format, rest) = Format::from_args(&args).unwrap();
+        assert!(format == Format::Json);
+        assert!(rest == ["7"]);
+
+        assert!(Format::from_args(&args[..2]).is_err());
+    }
+}
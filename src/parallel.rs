@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::error::{Error, Result};
+
+/// Pull "--jobs N" out of the command line, leaving the rest.  Defaults to 1.
+pub fn jobs_from_args(args: &[String]) -> Result<(usize, Vec<String>)> {
+    let mut jobs: usize = 1;
+    let mut rest: Vec<String> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => {
+                jobs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| Error::usage("--jobs needs a positive number"))?;
+            }
+            _ => rest.push(arg.to_string()),
+        }
+    }
+
+    Ok((jobs, rest))
+}
+
+/// Run `work` on every item, on up to `jobs` threads.  Each result is handed
+/// to `done` in the same order as `items`, as soon as it and everything before
+/// it has finished - so the output is the same however many jobs there are.
+pub fn for_each_ordered<T, R, W, D>(items: &[T], jobs: usize, work: W, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    D: FnMut(&T, R),
+{
+    if jobs <= 1 {
+        for item in items {
+            done(item, work(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= items.len() || sender.send((i, work(&items[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold on to results which finish early until it's their turn
+        let mut finished: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut reported = 0;
+        for (i, result) in receiver {
+            finished[i] = Some(result);
+            while let Some(result) = finished.get_mut(reported).and_then(Option::take) {
+                done(&items[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_ordered() {
+        // Early items take longest, so finish last
+        let items: Vec<u64> = (0..8).collect();
+        let work = |&n: &u64| {
+            thread::sleep(Duration::from_millis(40 - 5 * n));
+            n * n
+        };
+
+        for &jobs in &[1, 3, 8, 20] {
+            let mut results: Vec<(u64, u64)> = Vec::new();
+            for_each_ordered(&items, jobs, work, |&n, square| results.push((n, square)));
+            assert!(results == items.iter().map(|&n| (n, n * n)).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_jobs_from_args() {
+        let args: Vec<String> = ["--jobs", "4", "7"].iter().map(|s| s.to_string()).collect();
+        let (jobs, rest) = jobs_from_args(&args).unwrap();
+        assert!(jobs == 4);
+        assert!(rest == ["7"]);
+
+        assert!(jobs_from_args(&args[2..]).unwrap().0 == 1);
+        assert!(jobs_from_args(&["--jobs".to_string(), "0".to_string()]).is_err());
+    }
+}
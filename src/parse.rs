@@ -0,0 +1,415 @@
+//! Pieces for parsing puzzle input.  A `Text` is a slice of the input that
+//! knows where it came from, so splitting it up keeps track of lines and
+//! columns, and any error made from a piece points at exactly that piece.
+
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::parse_num;
+
+/// Part of the input, and where it starts: line and column are 1-based, and
+/// there's no column for something that's a whole line (or several), as
+/// the line says it all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Text<'a> {
+    text: &'a str,
+    line: usize,
+    column: Option<usize>,
+}
+
+impl<'a> Text<'a> {
+    /// The whole input, less any byte order mark
+    pub fn new(input: &'a str) -> Text<'a> {
+        Text {
+            text: input.strip_prefix('\u{feff}').unwrap_or(input),
+            line: 1,
+            column: None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// A parse error here
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        self.locate(Error::parse(message))
+    }
+
+    /// Put an error here, unless it already knows where it is
+    pub fn locate(&self, error: Error) -> Error {
+        let error = match self.column {
+            Some(column) => error.at_column(column),
+            None => error,
+        };
+        error.on_line(self.line)
+    }
+
+    // The text from byte `start` to `end`, and where that is
+    fn slice(&self, start: usize, end: usize) -> Text<'a> {
+        if start == 0 && end == self.text.len() {
+            return *self;
+        }
+
+        let before = &self.text[..start];
+        let (line, column) = match before.rfind('\n') {
+            Some(newline) => (
+                self.line + before.matches('\n').count(),
+                before[newline + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column.unwrap_or(1) + before.chars().count()),
+        };
+        Text {
+            text: &self.text[start..end],
+            line,
+            column: Some(column),
+        }
+    }
+
+    // Where `part`, which came from this text, starts in it
+    fn offset(&self, part: &Text<'a>) -> usize {
+        part.text.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+
+    // A piece that starts at the beginning of a line, as a whole line
+    fn whole_lines(self) -> Text<'a> {
+        match self.column {
+            Some(1) => Text {
+                column: None,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    pub fn trim(&self) -> Text<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    /// The pieces between each `separator`
+    pub fn split(&self, separator: &str) -> Vec<Text<'a>> {
+        let mut pieces: Vec<Text<'a>> = Vec::new();
+        let mut start = 0;
+        for (i, _) in self.text.match_indices(separator) {
+            pieces.push(self.slice(start, i));
+            start = i + separator.len();
+        }
+        pieces.push(self.slice(start, self.text.len()));
+        pieces
+    }
+
+    /// Before and after the first `separator`, if there is one
+    pub fn split_once(&self, separator: &str) -> Option<(Text<'a>, Text<'a>)> {
+        let i = self.text.find(separator)?;
+        Some((
+            self.slice(0, i),
+            self.slice(i + separator.len(), self.text.len()),
+        ))
+    }
+
+    /// Before and after byte `mid`, like `str::split_at`
+    pub fn split_at(&self, mid: usize) -> (Text<'a>, Text<'a>) {
+        (self.slice(0, mid), self.slice(mid, self.text.len()))
+    }
+
+    /// Each line, without its "\n" or "\r\n".  A newline at the end finishes
+    /// the last line rather than starting an empty one.
+    pub fn lines(&self) -> Vec<Text<'a>> {
+        let mut lines: Vec<Text<'a>> = Vec::new();
+        let mut start = 0;
+        while start < self.text.len() {
+            let end = self.text[start..]
+                .find('\n')
+                .map_or(self.text.len(), |i| start + i);
+            let line = &self.text[start..end];
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            lines.push(self.slice(start, start + line.len()).whole_lines());
+            start = end + 1;
+        }
+        lines
+    }
+
+    /// Lines with something other than whitespace on them
+    pub fn non_empty_lines(&self) -> Vec<Text<'a>> {
+        self.lines()
+            .into_iter()
+            .filter(|line| !line.text.trim().is_empty())
+            .collect()
+    }
+
+    /// Runs of non-empty lines, separated by one or more blank lines
+    pub fn blocks(&self) -> Vec<Text<'a>> {
+        let mut blocks: Vec<Text<'a>> = Vec::new();
+        let mut block: Option<(usize, usize)> = None;
+        for line in self.lines() {
+            if line.text.trim().is_empty() {
+                if let Some((start, end)) = block.take() {
+                    blocks.push(self.slice(start, end).whole_lines());
+                }
+            } else {
+                let start = self.offset(&line);
+                let end = start + line.text.len();
+                block = Some((block.map_or(start, |(start, _)| start), end));
+            }
+        }
+        if let Some((start, end)) = block {
+            blocks.push(self.slice(start, end).whole_lines());
+        }
+        blocks
+    }
+
+    /// The pieces between runs of whitespace, newlines included
+    pub fn words(&self) -> Vec<Text<'a>> {
+        let mut words: Vec<Text<'a>> = Vec::new();
+        let mut start: Option<usize> = None;
+        for (i, c) in self.text.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    words.push(self.slice(s, i));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            words.push(self.slice(s, self.text.len()));
+        }
+        words
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Text<'a>> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|_| self.slice(prefix.len(), self.text.len()))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Text<'a>> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.slice(0, rest.len()))
+    }
+
+    /// Each char, and where it is
+    pub fn chars(&self) -> impl Iterator<Item = (Text<'a>, char)> + '_ {
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (self.slice(i, i + c.len_utf8()), c))
+    }
+
+    pub fn num<T: FromStr>(&self) -> Result<T> {
+        parse_num(self.text).map_err(|e| self.locate(e))
+    }
+
+    /// A list of numbers, e.g. "1,2,3"
+    pub fn nums<T: FromStr>(&self, separator: &str) -> Result<Vec<T>> {
+        self.split(separator).iter().map(Text::num).collect()
+    }
+
+    /// Parse each non-empty line.  Errors from `parse` are on that line if
+    /// they don't say where they are.
+    pub fn parse_lines<T>(&self, mut parse: impl FnMut(Text<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.non_empty_lines()
+            .into_iter()
+            .map(|line| parse(line).map_err(|e| line.locate(e)))
+            .collect()
+    }
+
+    /// Key/value pairs separated by whitespace, e.g. "ecl:gry pid:860033327"
+    pub fn fields(&self, separator: &str) -> Result<Vec<(Text<'a>, Text<'a>)>> {
+        self.words()
+            .iter()
+            .map(|word| {
+                word.split_once(separator).ok_or_else(|| {
+                    word.error(format!(
+                        "Expected 'key{}value', found '{}'",
+                        separator, word.text
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// A regex match of the whole text, or an error saying what was
+    /// expected, e.g. "'<op> <value>'"
+    pub fn captures(&self, regex: &Regex, expected: &str) -> Result<Captures<'a>> {
+        let captures = regex
+            .captures(self.text)
+            .filter(|c| c[0].len() == self.text.len())
+            .ok_or_else(|| self.error(format!("Expected {}, found '{}'", expected, self.text)))?;
+        Ok(Captures {
+            text: *self,
+            captures,
+        })
+    }
+
+    /// A grid of chars - see `Grid::parse`
+    pub fn grid<T>(&self, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        Grid::parse(self.text, cell).map_err(|e| e.after_line(self.line - 1))
+    }
+}
+
+impl<'a> From<&'a str> for Text<'a> {
+    fn from(input: &'a str) -> Text<'a> {
+        Text::new(input)
+    }
+}
+
+/// The groups of a regex match, as `Text`s
+pub struct Captures<'a> {
+    text: Text<'a>,
+    captures: regex::Captures<'a>,
+}
+
+impl<'a> Captures<'a> {
+    pub fn group(&self, i: usize) -> Result<Text<'a>> {
+        let found = self
+            .captures
+            .get(i)
+            .ok_or_else(|| self.text.error(format!("Nothing matched group {}", i)))?;
+        Ok(self.text.slice(found.start(), found.end()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let input = Text::new("a = 1\nbb = 22\n\nccc = x");
+        let lines = input.lines();
+        assert!(lines.len() == 4);
+        assert!(lines[1].as_str() == "bb = 22" && lines[1].line() == 2);
+        assert!(lines[1].column().is_none());
+
+        let (key, value) = lines[1].split_once(" = ").unwrap();
+        assert!(key.as_str() == "bb" && key.column() == Some(1));
+        assert!(value.as_str() == "22" && value.column() == Some(6));
+        assert!(value.num::<u32>().unwrap() == 22);
+
+        let blocks = input.blocks();
+        assert!(blocks.len() == 2);
+        assert!(blocks[1].line() == 4 && blocks[1].column().is_none());
+        let (_, value) = blocks[1].split_once(" = ").unwrap();
+        let error = value.num::<u32>().err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 4, column 7: Expected a number, found 'x'"
+        );
+
+        // Whole lines are errors on the line
+        let error = lines[0].num::<u32>().err().unwrap();
+        assert!(error.to_string() == "Parse error at line 1: Expected a number, found 'a = 1'");
+
+        let words = blocks[0].words();
+        assert!(words.len() == 6);
+        assert!(words[4].as_str() == "=" && words[4].line() == 2 && words[4].column() == Some(4));
+        let trimmed = Text::new("  x \n").trim();
+        assert!(trimmed.as_str() == "x" && trimmed.column() == Some(3));
+
+        let (c, _) = Text::new("é!").chars().nth(1).unwrap();
+        assert!(c.column() == Some(2));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        // Windows line endings, a byte order mark and blank lines at the end
+        // read the same as plain "\n"s
+        let input = Text::new("\u{feff}1\r\n2\r\n\r\n3\r\n\r\n\r\n");
+        let lines: Vec<&str> = input.lines().iter().map(Text::as_str).collect();
+        assert!(lines == vec!["1", "2", "", "3", "", ""]);
+        assert!(input.non_empty_lines()[2].line() == 4);
+        assert!(input.parse_lines(|line| line.num::<u32>()).unwrap() == vec![1, 2, 3]);
+        assert!(Text::new("1\n2\n").lines().len() == 2);
+        assert!(Text::new("").lines().is_empty());
+
+        let blocks = Text::new("\na\nb\n\n \n\nc\n").blocks();
+        assert!(blocks.len() == 2);
+        assert!(blocks[0].as_str() == "a\nb" && blocks[0].column().is_none());
+        assert!(blocks[1].as_str() == "c" && blocks[1].line() == 7);
+        let blocks = Text::new("a\r\n\r\nb\r\nc").blocks();
+        assert!(blocks[1].lines()[1].as_str() == "c");
+
+        let error = Text::new("\u{feff}12\r\n3x")
+            .nums::<u32>("\n")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 1, column 1: Expected a number, found '12\r'"
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        let nums = Text::new("7,13,x").split(",");
+        assert!(nums[1].column() == Some(3));
+        assert!(Text::new("1,2,3").nums::<u32>(",").unwrap() == vec![1, 2, 3]);
+        let error = Text::new("1,2,x").nums::<u32>(",").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 1, column 5: Expected a number, found 'x'"
+        );
+
+        let fields = Text::new("ecl:gry pid:8\nhgt:183cm").fields(":").unwrap();
+        assert!(fields.len() == 3);
+        assert!(fields[2].0.as_str() == "hgt" && fields[2].1.line() == 2);
+        let error = Text::new("ecl:gry\npid").fields(":").err().unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 2, column 1: Expected 'key:value', found 'pid'"
+        );
+
+        // Errors with no position of their own are put on their line
+        let error = Text::new("1\nx")
+            .parse_lines(|line| match line.as_str() {
+                "x" => Err(Error::parse("Not x")),
+                _ => Ok(()),
+            })
+            .err()
+            .unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Not x");
+
+        assert!(Text::new("mem[8]").strip_prefix("mem[").unwrap().column() == Some(5));
+        assert!(Text::new("mem[8]").strip_suffix("]").unwrap().column() == Some(1));
+        let (_, value) = Text::new("F10").split_at(1);
+        assert!(value.as_str() == "10" && value.column() == Some(2));
+    }
+
+    #[test]
+    fn test_captures() {
+        let regex = Regex::new(r"(\d+)-(\d+) ([a-z])").unwrap();
+        let captures = Text::new("1-3 a").captures(&regex, "'a-b c'").unwrap();
+        assert!(captures.group(2).unwrap().num::<u32>().unwrap() == 3);
+        assert!(captures.group(3).unwrap().column() == Some(5));
+
+        let line = Text::new("x\n1-3 a!").lines()[1];
+        let error = line.captures(&regex, "'a-b c'").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Expected 'a-b c', found '1-3 a!'");
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = Text::new("3\n\n.#\n#x");
+        let error = input.blocks()[1].grid(|c| Some(c).filter(|&c| c != 'x'));
+        assert!(
+            error.err().unwrap().to_string()
+                == "Parse error at line 4, column 2: Unexpected char: 'x'"
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::solution::{Extra, Part, Solution};
+
+/// A setting for `Solution::with_param`, as (name, value)
+pub type Param = (String, String);
+
+/// A setting from the command line, and the parts it's for - every one run,
+/// if it doesn't say
+pub type ScopedParam = (Option<Vec<Target>>, Param);
+
+/// A registered day, and a type-erased way of running either part.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub parts: &'static [Part],
+    run: fn(&str, Part, &[Param]) -> Result<Run>,
+}
+
+/// The answer to one part, how long it took to get there, and anything else
+/// it made (see `Solution::extras`)
+pub struct Run {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub extras: Vec<Extra>,
+}
+
+impl Run {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl Day {
+    pub fn new<S: Solution>(year: u32, day: u32) -> Day {
+        Day {
+            year,
+            day,
+            parts: if S::HAS_PART_TWO {
+                &[Part::One, Part::Two]
+            } else {
+                &[Part::One]
+            },
+            run: run::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> Result<Run> {
+        (self.run)(input, part, &[])
+    }
+
+    // With some of `Solution::with_param`'s settings changed
+    pub fn run_with(&self, input: &str, part: Part, params: &[Param]) -> Result<Run> {
+        (self.run)(input, part, params)
+    }
+}
+
+/// Pull "--param name=value" out of the command line (as many as you like),
+/// leaving the rest.  Each is applied to every part run, with
+/// `Solution::with_param` - or only to those matching a spec in front of it,
+/// as for `select`, e.g. "--param 3:render=true".
+pub fn params_from_args(days: &[Day], args: &[String]) -> Result<(Vec<ScopedParam>, Vec<String>)> {
+    let mut params: Vec<ScopedParam> = Vec::new();
+    let mut rest: Vec<String> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--param" => {
+                let (name, value) = args
+                    .next()
+                    .and_then(|param| param.split_once('='))
+                    .ok_or_else(|| Error::usage("--param needs a '[spec:]name=value'"))?;
+                let (targets, name) = match name.split_once(':') {
+                    Some((spec, name)) => {
+                        let targets = select(days, spec).ok_or_else(|| {
+                            Error::usage(format!("No target for '{}' in --param", spec))
+                        })?;
+                        (Some(targets), name)
+                    }
+                    None => (None, name),
+                };
+                params.push((targets, (name.to_string(), value.to_string())));
+            }
+            _ => rest.push(arg.to_string()),
+        }
+    }
+
+    Ok((params, rest))
+}
+
+/// The settings from the command line for one part
+pub fn params_for(params: &[ScopedParam], target: Target) -> Vec<Param> {
+    params
+        .iter()
+        .filter(|(targets, _)| targets.as_ref().is_none_or(|t| t.contains(&target)))
+        .map(|(_, param)| param.clone())
+        .collect()
+}
+
+fn run<S: Solution>(input: &str, part: Part, params: &[Param]) -> Result<Run> {
+    let start = Instant::now();
+    let mut solution = S::parse(input)?;
+    for (name, value) in params {
+        solution = solution.with_param(name, value)?;
+    }
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => solution.part_one()?.to_string(),
+        Part::Two => solution.part_two()?.to_string(),
+    };
+    let solve = start.elapsed();
+
+    Ok(Run {
+        answer,
+        parse,
+        solve,
+        extras: solution.extras(part)?,
+    })
+}
+
+/// A single runnable part, e.g. "2020/7b"
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Target {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{}/{}{}", self.year, self.day, self.part.suffix()))
+    }
+}
+
+impl Target {
+    // e.g. "2020/7b", or "7b" for day 7 of `year`
+    pub fn parse(s: &str, year: u32) -> Option<Target> {
+        let (year, s) = match s.split_once('/') {
+            Some((year, s)) => (year.parse().ok()?, s),
+            None => (year, s),
+        };
+        let part = Part::from_suffix(s.chars().last()?)?;
+        let day = s[..s.len() - 1].parse::<u32>().ok()?;
+        Some(Target { year, day, part })
+    }
+}
+
+/// Every year's days, in order.  Adding a year means adding a line here
+/// (`new-day` does it for you).
+pub fn days() -> Vec<Day> {
+    let mut days = Vec::new();
+    days.extend(crate::y2020::days());
+    days
+}
+
+/// The year a spec without one means
+pub fn latest_year(days: &[Day]) -> u32 {
+    days.iter().map(|d| d.year).max().unwrap_or(0)
+}
+
+/// Every registered part, in order
+pub fn targets(days: &[Day]) -> Vec<Target> {
+    days.iter()
+        .flat_map(|d| {
+            d.parts.iter().map(move |&part| Target {
+                year: d.year,
+                day: d.day,
+                part,
+            })
+        })
+        .collect()
+}
+
+/// Pick out the registered parts matching a spec:
+///   "all" - everything, in every year
+///   "7b" / "7" - one part / both parts of a day
+///   "10-15" / "10-15a" - a range of days
+///   "*b" - one part of every day
+/// Those are for the latest year; prefix them with a year ("2020/7b") or
+/// "*/" for every year ("*/25a") to pick another.
+pub fn select(days: &[Day], spec: &str) -> Option<Vec<Target>> {
+    let all = targets(days);
+    if spec == "all" {
+        return Some(all);
+    }
+
+    let (year, spec) = match spec.split_once('/') {
+        Some(("*", spec)) => (None, spec),
+        Some((year, spec)) => (Some(year.parse().ok()?), spec),
+        None => (Some(latest_year(days)), spec),
+    };
+
+    let (range, part) = match spec.chars().last().and_then(Part::from_suffix) {
+        Some(part) => (&spec[..spec.len() - 1], Some(part)),
+        None => (spec, None),
+    };
+    let (first, last) = match range {
+        "*" => (u32::MIN, u32::MAX),
+        range => match range.split_once('-') {
+            Some((first, last)) => (first.parse().ok()?, last.parse().ok()?),
+            None => {
+                let day = range.parse().ok()?;
+                (day, day)
+            }
+        },
+    };
+
+    let selected: Vec<Target> = all
+        .into_iter()
+        .filter(|t| year.is_none_or(|y| y == t.year))
+        .filter(|t| first <= t.day && t.day <= last)
+        .filter(|t| part.is_none_or(|p| p == t.part))
+        .collect();
+    match selected.len() {
+        0 => None,
+        _ => Some(selected),
+    }
+}
+
+/// All the parts matched by any of the specs, in the order asked for
+pub fn select_all(days: &[Day], specs: &[&str]) -> Result<Vec<Target>> {
+    let mut targets: Vec<Target> = Vec::new();
+    for spec in specs {
+        let selected =
+            select(days, spec).ok_or_else(|| Error::usage(format!("No target for '{}'", spec)))?;
+        for target in selected {
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+
+    Ok(targets)
+}
+
+pub fn find(days: &[Day], target: Target) -> Result<&Day> {
+    days.iter()
+        .find(|d| d.year == target.year && d.day == target.day)
+        .ok_or_else(|| Error::usage(format!("No target for '{}'", target)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2020::day01::DayOne;
+
+    #[test]
+    fn test_target() {
+        let target = Target {
+            year: 2020,
+            day: 12,
+            part: Part::One,
+        };
+        assert!(target.to_string() == "2020/12a");
+        assert!(Target::parse("2020/12a", 2015) == Some(target));
+        assert!(Target::parse("12a", 2020) == Some(target));
+
+        assert!(Target::parse("12c", 2020).is_none());
+        assert!(Target::parse("b", 2020).is_none());
+        assert!(Target::parse("x/12a", 2020).is_none());
+        assert!(Target::parse("", 2020).is_none());
+    }
+
+    #[test]
+    fn test_select() {
+        let days = days();
+        let select = |spec| {
+            select(&days, spec).map(|ts| ts.iter().map(Target::to_string).collect::<Vec<_>>())
+        };
+
+        assert!(select("2020/7b").unwrap() == ["2020/7b"]);
+        assert!(select("2020/7").unwrap() == ["2020/7a", "2020/7b"]);
+        assert!(select("2020/10-11").unwrap() == ["2020/10a", "2020/10b", "2020/11a", "2020/11b"]);
+        assert!(select("2020/23-25b").unwrap() == ["2020/23b", "2020/24b"]);
+        assert!(select("2020/*a").unwrap().len() == 25);
+        assert!(select("2020/*b").unwrap().len() == 24);
+        assert!(select("all").unwrap().len() == targets(&days).len());
+
+        assert!(select("2020/25b").is_none());
+        assert!(select("2020/26").is_none());
+        assert!(select("2020/7c").is_none());
+        assert!(select("2019/7").is_none());
+        assert!(select("").is_none());
+        assert!(select("x-y").is_none());
+    }
+
+    #[test]
+    fn test_years() {
+        // Bare specs mean the latest year
+        let days = vec![
+            Day::new::<DayOne>(2020, 1),
+            Day::new::<DayOne>(2021, 1),
+            Day::new::<DayOne>(2021, 2),
+        ];
+        let select = |spec| {
+            select(&days, spec).map(|ts| ts.iter().map(Target::to_string).collect::<Vec<_>>())
+        };
+
+        assert!(latest_year(&days) == 2021);
+        assert!(select("1a").unwrap() == ["2021/1a"]);
+        assert!(select("2020/1a").unwrap() == ["2020/1a"]);
+        assert!(select("*/1a").unwrap() == ["2020/1a", "2021/1a"]);
+        assert!(select("2020/2").is_none());
+        assert!(select("all").unwrap().len() == 6);
+
+        let target = Target::parse("2020/1b", 2021).unwrap();
+        assert!(find(&days, target).unwrap().year == 2020);
+    }
+
+    #[test]
+    fn test_params() {
+        let days = days();
+        let args: Vec<String> = [
+            "1a",
+            "3",
+            "--param",
+            "k=3",
+            "--param",
+            "1:target=9",
+            "--param",
+            "3b:render=true",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let (params, rest) = params_from_args(&days, &args).unwrap();
+        assert!(rest == ["1a", "3"]);
+        assert!(params[1].1 == ("target".to_string(), "9".to_string()));
+        assert!(params_from_args(&days, &args[..3]).is_err());
+        let bad: Vec<String> = vec!["--param".to_string(), "26:k=3".to_string()];
+        assert!(matches!(
+            params_from_args(&days, &bad),
+            Err(Error::Usage(_))
+        ));
+
+        // Each part only gets the ones for it
+        let names = |target| -> Vec<String> {
+            let target = Target::parse(target, 2020).unwrap();
+            params_for(&params, target)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        };
+        assert!(names("1a") == ["k", "target"]);
+        assert!(names("3a") == ["k"]);
+        assert!(names("3b") == ["k", "render"]);
+
+        let day = Day::new::<DayOne>(2020, 1);
+        let target = Target::parse("1a", 2020).unwrap();
+        let run = day
+            .run_with("1\n2\n3\n4", Part::One, &params_for(&params, target))
+            .unwrap();
+        assert!(run.answer == "24");
+    }
+
+    #[test]
+    fn test_registry() {
+        let days: Vec<Day> = days().into_iter().filter(|d| d.year == 2020).collect();
+        assert!(days.iter().enumerate().all(|(i, d)| d.day == i as u32 + 1));
+        assert!(targets(&days).len() == 49);
+    }
+}
@@ -0,0 +1,307 @@
+use std::fs;
+use std::path::Path;
+
+use crate::answers::answers_file;
+use crate::error::{Error, Result};
+use crate::examples::{self, examples_file};
+use crate::read_file;
+use crate::registry::{self, Day};
+
+const TEMPLATE_FILE: &str = "src/template.rs";
+const LIB_FILE: &str = "src/lib.rs";
+const REGISTRY_FILE: &str = "src/registry.rs";
+
+// The first Advent of Code
+const FIRST_YEAR: u32 = 2015;
+
+const ONES: [&str; 10] = [
+    "", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+];
+const TEENS: [&str; 10] = [
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+];
+
+// e.g. 21 -> "DayTwentyOne", matching the existing days
+pub fn struct_name(day: u32) -> String {
+    let number = match day {
+        1..=9 => ONES[day as usize].to_string(),
+        10..=19 => TEENS[day as usize - 10].to_string(),
+        _ => format!("Twenty{}", ONES[day as usize - 20]),
+    };
+    format!("Day{}", number)
+}
+
+pub fn module_name(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+pub fn year_dir(year: u32) -> String {
+    format!("src/y{}", year)
+}
+
+// The template with its struct renamed for the day
+pub fn module_source(template: &str, day: u32) -> String {
+    template.replace("Template", &struct_name(day))
+}
+
+// The mod.rs for a year we haven't seen before, with its first day
+pub fn year_source(year: u32, day: u32) -> String {
+    format!(
+        "//! Advent of Code {year}
+
+use crate::registry::Day;
+
+pub mod {module};
+
+const YEAR: u32 = {year};
+
+/// All this year's days, in order.  `new-day` adds to this list.
+pub fn days() -> Vec<Day> {{
+    vec![{line}]
+}}
+",
+        year = year,
+        module = module_name(day),
+        line = day_line(day)
+    )
+}
+
+fn day_line(day: u32) -> String {
+    format!(
+        "Day::new::<{}::{}>(YEAR, {})",
+        module_name(day),
+        struct_name(day),
+        day
+    )
+}
+
+// Add "pub mod dayNN;" and the day's line in days() to a year's mod.rs
+pub fn register_day(year_mod: &str, day: u32) -> Result<String> {
+    let year_mod = insert_sorted(year_mod, &format!("pub mod {};", module_name(day)))?;
+
+    // rustfmt puts a list of one day on one line, so undo that first
+    let mut lines: Vec<String> = year_mod.lines().map(str::to_string).collect();
+    if let Some(i) = lines
+        .iter()
+        .position(|l| l.starts_with("    vec![Day::new::<"))
+    {
+        let only = lines[i]["    vec![".len()..lines[i].len() - 1].to_string();
+        lines.splice(
+            i..=i,
+            vec![
+                "    vec![".to_string(),
+                format!("        {},", only),
+                "    ]".to_string(),
+            ],
+        );
+    }
+
+    insert_sorted(
+        &(lines.join("\n") + "\n"),
+        &format!("        {},", day_line(day)),
+    )
+}
+
+// Add "pub mod yYYYY;" to lib.rs
+pub fn register_year_module(lib: &str, year: u32) -> Result<String> {
+    insert_sorted(lib, &format!("pub mod y{};", year))
+}
+
+// Add the year's days to registry::days()
+pub fn register_year(registry: &str, year: u32) -> Result<String> {
+    insert_sorted(
+        registry,
+        &format!("    days.extend(crate::y{}::days());", year),
+    )
+}
+
+// Insert a line among the others like it (up to the first digit), keeping
+// them in order.  The new line mustn't be there already.
+fn insert_sorted(text: &str, line: &str) -> Result<String> {
+    let prefix = &line[..line
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(line.len())];
+    let mut lines: Vec<&str> = text.lines().collect();
+    let similar: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+
+    if similar.iter().any(|&i| lines[i] == line) {
+        return Err(Error::usage(format!("'{}' is already there", line.trim())));
+    }
+    let at = match similar.iter().rev().find(|&&i| lines[i] < line) {
+        Some(&i) => i + 1,
+        None => *similar
+            .first()
+            .ok_or_else(|| Error::usage(format!("Couldn't find where to put '{}'", line.trim())))?,
+    };
+
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write_new(filename: &str, contents: &str) -> Result<()> {
+    fs::write(filename, contents).map_err(|source| Error::Io {
+        path: filename.to_string(),
+        source,
+    })
+}
+
+fn create_dir(dir: &str) -> Result<()> {
+    fs::create_dir_all(dir).map_err(|source| Error::Io {
+        path: dir.to_string(),
+        source,
+    })
+}
+
+// "2021/7", or "7" for the latest year
+fn parse_spec(days: &[Day], spec: &str) -> Option<(u32, u32)> {
+    let (year, day) = match spec.split_once('/') {
+        Some((year, day)) => (year.parse().ok()?, day),
+        None => (registry::latest_year(days), spec),
+    };
+    let day = day.parse().ok()?;
+
+    if year >= FIRST_YEAR && (1..=25).contains(&day) {
+        Some((year, day))
+    } else {
+        None
+    }
+}
+
+// e.g. "new-day 2020/7": writes src/y2020/day07.rs from the template, creates
+// empty data/2020/7.txt and data/2020/7_example.txt, and registers the day -
+// along with the year, if it's a new one.  The example's answers go in
+// data/2020/examples.txt.
+pub fn main(days: &[Day], args: &[String]) -> Result<usize> {
+    let usage = || Error::usage("new-day needs a day from 1 to 25, e.g. 2020/7 or 7");
+    let (year, day) = match args {
+        [spec] => parse_spec(days, spec).ok_or_else(usage)?,
+        _ => return Err(usage()),
+    };
+
+    let dir = year_dir(year);
+    let filename = format!("{}/{}.rs", dir, module_name(day));
+    if Path::new(&filename).exists() {
+        return Err(Error::usage(format!("{} already exists", filename)));
+    }
+
+    // Work everything out before writing anything, so a failure leaves no mess
+    let source = module_source(&read_file(TEMPLATE_FILE)?, day);
+    let year_mod = format!("{}/mod.rs", dir);
+    let mut changes: Vec<(String, String)> = Vec::new();
+    if Path::new(&year_mod).exists() {
+        let registered = register_day(&read_file(&year_mod)?, day)?;
+        changes.push((year_mod, registered));
+    } else {
+        changes.push((year_mod, year_source(year, day)));
+        let lib = register_year_module(&read_file(LIB_FILE)?, year)?;
+        changes.push((LIB_FILE.to_string(), lib));
+        let registry = register_year(&read_file(REGISTRY_FILE)?, year)?;
+        changes.push((REGISTRY_FILE.to_string(), registry));
+    }
+
+    create_dir(&dir)?;
+    write_new(&filename, &source)?;
+    println!("Created {}", filename);
+    for (filename, contents) in changes {
+        write_new(&filename, &contents)?;
+        println!("Wrote {}", filename);
+    }
+
+    // Inputs and answers go in a directory per year too
+    create_dir(&format!("data/{}", year))?;
+    let data = [
+        (format!("data/{}/{}.txt", year, day), ""),
+        (format!("data/{}/{}_example.txt", year, day), ""),
+        (answers_file(year), ""),
+        (examples_file(year), examples::HEADER),
+    ];
+    for (data, contents) in &data {
+        if !Path::new(data).exists() {
+            write_new(data, contents)?;
+            println!("Created {}", data);
+        }
+    }
+
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert!(struct_name(1) == "DayOne");
+        assert!(struct_name(13) == "DayThirteen");
+        assert!(struct_name(20) == "DayTwenty");
+        assert!(struct_name(25) == "DayTwentyFive");
+        assert!(module_name(7) == "day07");
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let days = registry::days();
+        assert!(parse_spec(&days, "2021/7") == Some((2021, 7)));
+        assert!(parse_spec(&days, "7") == Some((registry::latest_year(&days), 7)));
+        assert!(parse_spec(&days, "2021/26").is_none());
+        assert!(parse_spec(&days, "2014/1").is_none());
+        assert!(parse_spec(&days, "x/1").is_none());
+    }
+
+    #[test]
+    fn test_module_source() {
+        let source = module_source(&read_file(TEMPLATE_FILE).unwrap(), 7);
+        assert!(source.contains("pub struct DaySeven {"));
+        assert!(source.contains("impl Solution for DaySeven {"));
+        assert!(!source.contains("Template"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        // Taking a day out of the real file and adding it back gives the same file
+        let year_mod = read_file("src/y2020/mod.rs").unwrap();
+        let without = year_mod
+            .replace("pub mod day07;\n", "")
+            .replace("        Day::new::<day07::DaySeven>(YEAR, 7),\n", "");
+        assert!(without != year_mod);
+        assert!(register_day(&without, 7).unwrap() == year_mod);
+        assert!(register_day(&year_mod, 7).is_err());
+
+        // A new year starts with one day, and grows
+        let year_mod = register_day(&year_source(2021, 3), 1).unwrap();
+        assert!(year_mod.contains("pub mod day01;\npub mod day03;\n"));
+        assert!(year_mod.contains(
+            "    vec![
+        Day::new::<day01::DayOne>(YEAR, 1),
+        Day::new::<day03::DayThree>(YEAR, 3),
+    ]"
+        ));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = "pub mod solution;\npub mod y2016;\npub mod y2020;\n";
+        let registered = register_year_module(lib, 2017).unwrap();
+        assert!(
+            registered == "pub mod solution;\npub mod y2016;\npub mod y2017;\npub mod y2020;\n"
+        );
+        assert!(register_year_module(lib, 2020).is_err());
+        assert!(register_year_module("pub mod solution;\n", 2020).is_err());
+
+        let registry = read_file(REGISTRY_FILE).unwrap();
+        let registered = register_year(&registry, 2014).unwrap();
+        assert!(registered.contains("    days.extend(crate::y2014::days());\n"));
+        assert!(register_year(&registry, 2020).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+
+/// One day's puzzle.  The struct holds the parsed input, and each part is
+/// answered from that - so the (sometimes slow) parse only happens once.
+pub trait Solution: Sized {
+    type PartOne: fmt::Display;
+    type PartTwo: fmt::Display;
+
+    /// Day 25 only has one part
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    fn part_one(&self) -> Result<Self::PartOne>;
+
+    fn part_two(&self) -> Result<Self::PartTwo>;
+
+    /// Change a setting that's fixed for the real puzzle but not for some of
+    /// its examples, like day 9's preamble length.  Most days have none.
+    fn with_param(self, name: &str, _value: &str) -> Result<Self> {
+        Err(Error::usage(format!(
+            "No parameter '{}' for this day",
+            name
+        )))
+    }
+
+    /// What else `part` makes besides its answer when a parameter asks for
+    /// it, like a drawing of day 3's path.  Only the command line shows or
+    /// saves these, so checking answers or examples never does.
+    fn extras(&self, _part: Part) -> Result<Vec<Extra>> {
+        Ok(Vec::new())
+    }
+
+    // For tests - panics if the file is missing or bad
+    #[cfg(test)]
+    fn from_file(filename: &str) -> Self {
+        Self::parse(&crate::read_file(filename).unwrap()).unwrap()
+    }
+}
+
+/// Something a part makes besides its answer
+#[derive(Debug, PartialEq)]
+pub enum Extra {
+    Show(String), // For the user, on stderr
+    File { path: String, contents: String },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_suffix(c: char) -> Option<Part> {
+        match c {
+            'a' => Some(Part::One),
+            'b' => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn suffix(self) -> char {
+        match self {
+            Part::One => 'a',
+            Part::Two => 'b',
+        }
+    }
+}
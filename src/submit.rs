@@ -0,0 +1,634 @@
+//! Sending answers to the site.  Every attempt is kept in a history file per
+//! year, so we never send an answer we already know is wrong, and we respect
+//! the site's "please wait" between attempts without having to ask it again.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::fetch::site_from_env;
+use crate::http::{self, Http, USER_AGENT};
+use crate::input::Source;
+use crate::registry::{self, Day, Target};
+use crate::solution::Part;
+
+lazy_static! {
+    // e.g. "You have 1m 23s left to wait." or "You have 45s left to wait."
+    static ref LEFT_TO_WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    // e.g. "please wait one minute before trying again" or "5 minutes"
+    static ref WAIT_MINUTES: Regex = Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+}
+
+pub fn history_file(year: u32) -> String {
+    format!("data/{}/submissions.txt", year)
+}
+
+/// What the site made of an answer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Too soon since the last attempt; the answer wasn't checked
+    Wait,
+    // This part is already solved, or not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    // From the HTML of the page the site sends back
+    pub fn parse(page: &str) -> Outcome {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::Wait
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait => "wait",
+            Outcome::WrongLevel => "wrong-level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::Wait,
+            Outcome::WrongLevel,
+            Outcome::Unknown,
+        ]
+        .iter()
+        .copied()
+        .find(|o| o.name() == name)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Outcome::Correct => "That's the right answer!",
+            Outcome::TooHigh => "Wrong - too high",
+            Outcome::TooLow => "Wrong - too low",
+            Outcome::Wrong => "Wrong",
+            Outcome::Wait => "Too soon since the last answer - not checked",
+            Outcome::WrongLevel => "Already solved, or not unlocked yet - not checked",
+            Outcome::Unknown => "Couldn't make sense of the response",
+        })
+    }
+}
+
+// How long the page (from `url`) says to wait before the next attempt, in
+// seconds.  A wait too long to hold is an error, not a page without one.
+pub fn parse_wait(url: &str, page: &str) -> Result<Option<u64>> {
+    let too_long = |wait: &str| Error::Fetch {
+        url: url.to_string(),
+        message: format!("Can't wait for '{}'", wait),
+    };
+    let seconds = |minutes: &str, seconds: &str| -> Option<u64> {
+        minutes
+            .parse::<u64>()
+            .ok()?
+            .checked_mul(60)?
+            .checked_add(seconds.parse().ok()?)
+    };
+
+    if let Some(captures) = LEFT_TO_WAIT.captures(page) {
+        let minutes = captures.get(1).map_or("0", |m| m.as_str());
+        return seconds(minutes, &captures[2])
+            .map(Some)
+            .ok_or_else(|| too_long(&captures[0]));
+    }
+    match WAIT_MINUTES.captures(page) {
+        Some(captures) => {
+            let minutes = match &captures[1] {
+                "one" => "1",
+                minutes => minutes,
+            };
+            seconds(minutes, "0")
+                .map(Some)
+                .ok_or_else(|| too_long(&captures[0]))
+        }
+        None => Ok(None),
+    }
+}
+
+/// One line of the history file: "<time>\t<target>\t<answer>\t<outcome>\t<wait>"
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    pub time: u64, // Seconds since the Unix epoch
+    pub target: Target,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub wait: u64, // Seconds until the next attempt is allowed
+}
+
+impl Attempt {
+    fn parse(line: &str) -> Option<Attempt> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields[..] {
+            [time, target, answer, outcome, wait] => Some(Attempt {
+                time: time.parse().ok()?,
+                target: Target::parse(target, 0)?,
+                answer: answer.to_string(),
+                outcome: Outcome::from_name(outcome)?,
+                wait: wait.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.target,
+            self.answer,
+            self.outcome.name(),
+            self.wait
+        )
+    }
+}
+
+/// Every answer sent so far
+pub struct History {
+    filename: String,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    // A missing file is an empty history, but one we can't read isn't - we
+    // might resend answers it says are wrong
+    pub fn load(filename: &str) -> Result<History> {
+        let contents = match fs::read_to_string(filename) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: filename.to_string(),
+                    source,
+                })
+            }
+        };
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                Attempt::parse(line).ok_or_else(|| {
+                    Error::parse(format!("Bad attempt in {}: '{}'", filename, line)).on_line(i + 1)
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(History {
+            filename: filename.to_string(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Why this answer shouldn't be sent at `now`, if there's a reason
+    pub fn refuse(&self, target: Target, answer: &str, now: u64) -> Option<String> {
+        let attempts: Vec<&Attempt> = self
+            .attempts
+            .iter()
+            .filter(|a| a.target == target)
+            .collect();
+
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!(
+                "{} was already solved with {}",
+                target, correct.answer
+            ));
+        }
+        if let Some(wrong) = attempts
+            .iter()
+            .find(|a| a.answer == answer && is_wrong(a.outcome))
+        {
+            return Some(format!(
+                "{} was already rejected for {} ({})",
+                answer, target, wrong.outcome
+            ));
+        }
+
+        // Numbers on the wrong side of a "too high" or "too low" are wrong too
+        if let Ok(number) = answer.parse::<i64>() {
+            for attempt in &attempts {
+                let bound = match attempt.answer.parse::<i64>() {
+                    Ok(bound) => bound,
+                    Err(_) => continue,
+                };
+                match attempt.outcome {
+                    Outcome::TooHigh if number >= bound => {
+                        return Some(format!("{} was too high for {}", bound, target))
+                    }
+                    Outcome::TooLow if number <= bound => {
+                        return Some(format!("{} was too low for {}", bound, target))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // The wait is for the whole site, not just this part
+        let until = self.attempts.iter().map(|a| a.time + a.wait).max()?;
+        if now < until {
+            return Some(format!(
+                "the site wants another {}s before the next answer",
+                until - now
+            ));
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        check_answer(&attempt.answer)?;
+        let io = |source| Error::Io {
+            path: self.filename.clone(),
+            source,
+        };
+        // The year's directory may not be there yet
+        if let Some(dir) = Path::new(&self.filename).parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.filename)
+            .map_err(io)?;
+        writeln!(file, "{}", attempt).map_err(io)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+// Tabs and line breaks would split the answer's line of the history file
+fn check_answer(answer: &str) -> Result<()> {
+    match answer.contains(['\t', '\n', '\r']) {
+        true => Err(Error::usage(format!(
+            "An answer can't have tabs or line breaks in it: {:?}",
+            answer
+        ))),
+        false => Ok(()),
+    }
+}
+
+fn is_wrong(outcome: Outcome) -> bool {
+    matches!(outcome, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+}
+
+/// Posts answers, logged in like the `Fetcher`
+pub struct Submitter {
+    base_url: String,
+    session: String,
+    http: Box<dyn Http>,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: &str, http: Box<dyn Http>) -> Submitter {
+        Submitter {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            http,
+        }
+    }
+
+    pub fn from_env() -> Result<Submitter> {
+        let (base_url, session) =
+            site_from_env().ok_or_else(|| Error::usage("Set AOC_SESSION to submit answers"))?;
+        Ok(Submitter::new(
+            &base_url,
+            &session,
+            http::backend(&base_url),
+        ))
+    }
+
+    // Returns what the site said, and how long it wants us to wait
+    pub fn submit(&self, target: Target, answer: &str) -> Result<(Outcome, u64)> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url, target.year, target.day
+        );
+        let level = match target.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let form = format!("level={}&answer={}", level, url_encode(answer));
+        let cookie = format!("session={}", self.session);
+
+        verbose!("Posting {} to {}", form, url);
+        let response = self.http.post(
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", USER_AGENT)],
+            &form,
+        )?;
+        if response.status != 200 {
+            return Err(Error::Fetch {
+                url,
+                message: format!("HTTP {} - check the token in AOC_SESSION", response.status),
+            });
+        }
+
+        Ok((
+            Outcome::parse(&response.body),
+            parse_wait(&url, &response.body)?.unwrap_or(0),
+        ))
+    }
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Check an answer against the history, send it if it might be right, and
+/// record what happened
+pub fn submit(
+    submitter: &Submitter,
+    history: &mut History,
+    target: Target,
+    answer: &str,
+    now: u64,
+) -> Result<Option<Outcome>> {
+    check_answer(answer)?;
+    if let Some(reason) = history.refuse(target, answer, now) {
+        println!("Not submitting {} for {}: {}", answer, target, reason);
+        return Ok(None);
+    }
+
+    let (outcome, wait) = submitter.submit(target, answer)?;
+    history.record(Attempt {
+        time: now,
+        target,
+        answer: answer.to_string(),
+        outcome,
+        wait,
+    })?;
+
+    match wait {
+        0 => println!("{}: {} - {}", target, answer, outcome),
+        wait => println!("{}: {} - {} (wait {}s)", target, answer, outcome, wait),
+    }
+    Ok(Some(outcome))
+}
+
+// e.g. "submit 2020/7b" to work out the answer and send it, or
+// "submit 2020/7b 220149" to send that
+// Returns 1 unless the answer was accepted
+pub fn main(days: &[Day], source: &Source, args: &[String]) -> Result<usize> {
+    let usage =
+        || Error::usage("submit needs a part, and optionally an answer, e.g. 2020/7b 220149");
+    let (spec, answer) = match args {
+        [spec] => (spec, None),
+        [spec, answer] => (spec, Some(answer.to_string())),
+        _ => return Err(usage()),
+    };
+    let target = Target::parse(spec, registry::latest_year(days)).ok_or_else(usage)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let day = registry::find(days, target)?;
+            let run = day.run(&source.read(target.year, target.day)?, target.part)?;
+            println!("Day {}: -> {}", target, run.answer);
+            run.answer
+        }
+    };
+
+    let submitter = Submitter::from_env()?;
+    let mut history = History::load(&history_file(target.year))?;
+    match submit(&submitter, &mut history, target, &answer, now())? {
+        Some(Outcome::Correct) => Ok(0),
+        _ => Ok(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+    use std::env;
+    use std::process;
+
+    fn target(s: &str) -> Target {
+        Target::parse(s, 2020).unwrap()
+    }
+
+    // A fresh, empty history file for each test
+    fn scratch(name: &str) -> String {
+        let path = env::temp_dir().join(format!("aoc-submit-{}-{}.txt", process::id(), name));
+        let _ = fs::remove_file(&path);
+        path.to_str().unwrap().to_string()
+    }
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn test_outcome() {
+        let too_high = "That's not the right answer; your answer is too high.  Please wait one minute before trying again.";
+        assert!(Outcome::parse(&page(too_high)) == Outcome::TooHigh);
+        let wait_for = |text| parse_wait("https://example.com", &page(text)).unwrap();
+        assert!(wait_for(too_high) == Some(60));
+
+        let wait = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.";
+        assert!(Outcome::parse(&page(wait)) == Outcome::Wait);
+        assert!(wait_for(wait) == Some(83));
+        assert!(wait_for("You have 45s left to wait.") == Some(45));
+        assert!(wait_for("please wait 5 minutes before trying again") == Some(300));
+
+        let right =
+            "That's the right answer!  You are one gold star closer to saving your vacation.";
+        assert!(Outcome::parse(&page(right)) == Outcome::Correct);
+        assert!(wait_for(right).is_none());
+
+        // Too long to hold
+        for text in &[
+            "You have 99999999999999999999s left to wait.",
+            "You have 307445734561825861m 0s left to wait.",
+            "please wait 99999999999999999999 minutes before trying again",
+        ] {
+            let error = parse_wait("https://example.com", &page(text));
+            assert!(matches!(error, Err(Error::Fetch { .. })));
+        }
+        assert!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )) == Outcome::WrongLevel
+        );
+        assert!(Outcome::parse("Something else") == Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_attempt() {
+        let attempt = Attempt {
+            time: 1608000000,
+            target: target("2020/21b"),
+            answer: "qqskn,ccvnlbp".to_string(),
+            outcome: Outcome::TooLow,
+            wait: 60,
+        };
+        let line = attempt.to_string();
+        assert!(line == "1608000000\t2020/21b\tqqskn,ccvnlbp\ttoo-low\t60");
+        assert!(Attempt::parse(&line) == Some(attempt));
+        assert!(Attempt::parse("1608000000\t2020/21b\tx\tmaybe\t0").is_none());
+
+        assert!(url_encode("qqskn,ccvnlbp") == "qqskn%2Cccvnlbp");
+    }
+
+    #[test]
+    fn test_refuse() {
+        let filename = scratch("refuse");
+        let mut history = History::load(&filename).unwrap();
+        let attempt = |answer: &str, outcome, wait| Attempt {
+            time: 1000,
+            target: target("2020/7b"),
+            answer: answer.to_string(),
+            outcome,
+            wait,
+        };
+        history
+            .record(attempt("500", Outcome::TooHigh, 60))
+            .unwrap();
+        history.record(attempt("100", Outcome::TooLow, 0)).unwrap();
+        history.record(attempt("abc", Outcome::Wrong, 0)).unwrap();
+
+        let refuse = |answer, now| history.refuse(target("2020/7b"), answer, now);
+        assert!(refuse("abc", 2000).unwrap().contains("already rejected"));
+        assert!(refuse("500", 2000).unwrap().contains("already rejected"));
+        assert!(refuse("600", 2000).unwrap().contains("500 was too high"));
+        assert!(refuse("50", 2000).unwrap().contains("100 was too low"));
+        assert!(refuse("300", 1030).unwrap().contains("another 30s"));
+        assert!(refuse("300", 2000).is_none());
+        assert!(history.refuse(target("2020/7a"), "500", 2000).is_none());
+
+        // It's all in the file
+        let history = History::load(&filename).unwrap();
+        assert!(history.attempts().len() == 3);
+
+        // The file's directory is made if it's missing
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-dir", process::id()));
+        let nested = dir.join("2020").join("submissions.txt");
+        let mut fresh = History::load(nested.to_str().unwrap()).unwrap();
+        fresh.record(attempt("500", Outcome::TooHigh, 60)).unwrap();
+        assert!(fresh.record(attempt("5\n0", Outcome::Wrong, 0)).is_err());
+        assert!(
+            History::load(nested.to_str().unwrap())
+                .unwrap()
+                .attempts()
+                .len()
+                == 1
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        // A file that's there but can't be read isn't taken as no attempts
+        fs::write(&filename, [0xff, 0xfe, b'\n']).unwrap();
+        assert!(matches!(History::load(&filename), Err(Error::Io { .. })));
+        fs::remove_file(&filename).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        // The real 7b answer is 220149
+        let server = stub::Server::start(|request| {
+            let body = match request.rsplit("\r\n").next().unwrap() {
+                "level=2&answer=220149" => page("That's the right answer!"),
+                "level=2&answer=300000" => page("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+                _ => page("You don't seem to be solving the right level."),
+            };
+            (200, body)
+        });
+        let submitter = Submitter::new(&server.url, "abc", Box::new(http::Plain));
+        let filename = scratch("submit");
+        let mut history = History::load(&filename).unwrap();
+        let seven = target("2020/7b");
+
+        let submit = |history: &mut History, answer, now| {
+            submit(&submitter, history, seven, answer, now).unwrap()
+        };
+        assert!(submit(&mut history, "300000", 1000) == Some(Outcome::TooHigh));
+        // Known wrong, or too soon - the site isn't asked
+        assert!(submit(&mut history, "300000", 2000).is_none());
+        assert!(submit(&mut history, "220149", 1030).is_none());
+        assert!(server.requests().len() == 1);
+        // Nor for one that can't go in the history
+        let error = super::submit(&submitter, &mut history, seven, "22\t0149", 1060);
+        assert!(matches!(error, Err(Error::Usage(_))));
+        assert!(server.requests().len() == 1);
+
+        assert!(submit(&mut history, "220149", 1060) == Some(Outcome::Correct));
+        assert!(submit(&mut history, "220149", 2000).is_none());
+
+        let requests = server.requests();
+        assert!(requests.len() == 2);
+        assert!(requests[1].starts_with("POST /2020/day/7/answer HTTP/1.0\r\n"));
+        assert!(requests[1].contains("\r\nCookie: session=abc\r\n"));
+
+        let history = History::load(&filename).unwrap();
+        assert!(history.attempts().len() == 2);
+        assert!(history.attempts()[0].wait == 60);
+        fs::remove_file(&filename).unwrap();
+
+        // A login problem is an error, and isn't recorded
+        let server = stub::Server::start(|_| (400, String::new()));
+        let submitter = Submitter::new(&server.url, "expired", Box::new(http::Plain));
+        assert!(matches!(
+            submitter.submit(seven, "1"),
+            Err(Error::Fetch { .. })
+        ));
+    }
+}
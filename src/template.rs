@@ -1,38 +1,33 @@
-use crate::file_to_vec;
+use crate::error::Result;
+use crate::parse::Text;
+use crate::solution::Solution;
 
 pub struct Template {
     _data: Vec<String>,
 }
 
-impl Template {
-    fn new(filename: &str) -> Self {
-        Template {
-            _data: file_to_vec(filename),
-        }
-    }
+impl Solution for Template {
+    type PartOne = i32;
+    type PartTwo = i32;
 
-    pub fn _default() -> Self {
-        Template::new("data/template.txt")
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Template {
+            _data: Text::new(input)
+                .lines()
+                .iter()
+                .map(|line| line.as_str().to_string())
+                .collect(),
+        })
     }
 
-    pub fn solve_one(&self) -> i32 {
+    fn part_one(&self) -> Result<i32> {
         // TODO
-        0
+        Ok(0)
     }
 
-    pub fn solve_two(&self) -> i32 {
+    fn part_two(&self) -> Result<i32> {
         // TODO
-        0
+        Ok(0)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        let template = Template::new("data/1a.txt");
-        assert!(template.solve_one() == 0);
-    }
-}
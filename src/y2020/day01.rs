@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::parse_num;
+use crate::solution::Solution;
+
+pub struct DayOne {
+    data: Vec<i64>, // Sorted
+    target: i64,
+    k: Option<usize>,       // Entries to add up, if not the part's usual 2 or 3
+    all: bool,              // Every combination, not just the first
+    stream: Option<String>, // A report to read a line at a time instead
+}
+
+impl Solution for DayOne {
+    type PartOne = Products;
+    type PartTwo = Products;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut data: Vec<i64> = Text::new(input).parse_lines(|line| line.num())?;
+        data.sort_unstable();
+        Ok(DayOne {
+            data,
+            target: 2020,
+            k: None,
+            all: false,
+            stream: None,
+        })
+    }
+
+    // The sum to look for, how many entries make it up, whether to find
+    // every combination that does, and a report to stream instead
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "target" => Ok(DayOne {
+                target: parse_num(value)?,
+                ..self
+            }),
+            "k" => match parse_num(value)? {
+                0 => Err(Error::usage("k must be at least 1")),
+                k => Ok(DayOne { k: Some(k), ..self }),
+            },
+            "all" => Ok(DayOne {
+                all: crate::parse_bool(name, value)?,
+                ..self
+            }),
+            "stream" => Ok(DayOne {
+                stream: Some(value.to_string()),
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 1", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<Products> {
+        self.solve(self.k.unwrap_or(2))
+    }
+
+    fn part_two(&self) -> Result<Products> {
+        self.solve(self.k.unwrap_or(3))
+    }
+}
+
+impl DayOne {
+    fn solve(&self, k: usize) -> Result<Products> {
+        if let Some(path) = &self.stream {
+            return self.solve_stream(path, k);
+        }
+
+        let mut products: Vec<i128> = Vec::new();
+        let mut overflow: Option<Error> = None;
+        search(
+            &self.data,
+            0,
+            k,
+            self.target.into(),
+            &mut Vec::new(),
+            &mut |entries| match product(entries) {
+                Ok(product) => {
+                    verbose!("{:?} -> {}", entries, product);
+                    products.push(product);
+                    self.all
+                }
+                Err(e) => {
+                    overflow = Some(e);
+                    false
+                }
+            },
+        );
+
+        match (overflow, products.is_empty()) {
+            (Some(e), _) => Err(e),
+            (None, true) => Err(Error::no_solution(format!(
+                "no {} entries sum to {}",
+                k, self.target
+            ))),
+            (None, false) => Ok(Products(products)),
+        }
+    }
+
+    // The first combination from the report at `path`, never all in memory.
+    // Read here rather than in `parse` (whose input goes unused), so the
+    // part's solve time includes the reading - `bench` and `verify` take no
+    // parameters, so never do this.
+    fn solve_stream(&self, path: &str, k: usize) -> Result<Products> {
+        if self.all {
+            return Err(Error::usage("stream only finds the first combination"));
+        }
+        let io_error = |source| Error::Io {
+            path: path.to_string(),
+            source,
+        };
+        let report = BufReader::new(File::open(path).map_err(io_error)?);
+        let entries = match stream_k_sum(report, k, self.target) {
+            Err(Error::Io { source, .. }) => return Err(io_error(source)),
+            found => found?.ok_or_else(|| {
+                Error::no_solution(format!("no {} entries sum to {}", k, self.target))
+            })?,
+        };
+        verbose!("{:?}", entries);
+        Ok(Products(vec![product(&entries)?]))
+    }
+}
+
+/// The product of some entries, or an error if it's too big to hold
+pub fn product(entries: &[i64]) -> Result<i128> {
+    entries
+        .iter()
+        .try_fold(1i128, |acc, &entry| acc.checked_mul(entry.into()))
+        .ok_or_else(|| Error::unsupported(format!("the product of {:?} is too big", entries)))
+}
+
+/// The product of each combination of entries, in the order they were found
+pub struct Products(pub Vec<i128>);
+
+impl fmt::Display for Products {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let products: Vec<String> = self.0.iter().map(i128::to_string).collect();
+        write!(f, "{}", products.join(","))
+    }
+}
+
+/// The first `k` entries (smallest first) that add up to `target`
+pub fn find_k_sum(values: &[i64], k: usize, target: i64) -> Option<Vec<i64>> {
+    let mut found: Option<Vec<i64>> = None;
+    let mut values = values.to_vec();
+    values.sort_unstable();
+    search(
+        &values,
+        0,
+        k,
+        target.into(),
+        &mut Vec::new(),
+        &mut |entries| {
+            found = Some(entries.to_vec());
+            false
+        },
+    );
+    found
+}
+
+/// Every combination of `k` entries that adds up to `target`.  An entry is
+/// only used once, but equal entries are different combinations.
+pub fn all_k_sums(values: &[i64], k: usize, target: i64) -> Vec<Vec<i64>> {
+    let mut found: Vec<Vec<i64>> = Vec::new();
+    let mut values = values.to_vec();
+    values.sort_unstable();
+    search(
+        &values,
+        0,
+        k,
+        target.into(),
+        &mut Vec::new(),
+        &mut |entries| {
+            found.push(entries.to_vec());
+            true
+        },
+    );
+    found
+}
+
+/// Like `find_k_sum`, but reading the report a line at a time and stopping
+/// at the first entry that completes a combination, so a huge report is
+/// never all in memory as text.  The combination is in the order it was
+/// read.  Each entry read is kept (and indexed), and k = 2 takes one pass;
+/// every extra entry to find multiplies the time by the entries so far.
+pub fn stream_k_sum(report: impl BufRead, k: usize, target: i64) -> Result<Option<Vec<i64>>> {
+    if k == 0 {
+        return Err(Error::usage("k must be at least 1"));
+    }
+
+    let mut seen: Vec<i64> = Vec::new();
+    let mut positions: HashMap<i64, Vec<usize>> = HashMap::new();
+    for (i, line) in report.lines().enumerate() {
+        let line = line.map_err(|source| Error::Io {
+            path: "report".to_string(),
+            source,
+        })?;
+        let line = Text::new(&line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let entry: i64 = line.num().map_err(|e| e.after_line(i))?;
+
+        let mut chosen = vec![entry];
+        let rest = i128::from(target) - i128::from(entry);
+        if complete(&seen, &positions, seen.len(), k - 1, rest, &mut chosen) {
+            chosen.reverse();
+            return Ok(Some(chosen));
+        }
+        positions.entry(entry).or_default().push(seen.len());
+        seen.push(entry);
+    }
+
+    Ok(None)
+}
+
+// Choose `k` of the first `end` entries `seen`, adding up to `target`, onto
+// `chosen` (latest first).  The last is looked up by value in `positions`,
+// the others are tried in turn.
+fn complete(
+    seen: &[i64],
+    positions: &HashMap<i64, Vec<usize>>,
+    end: usize,
+    k: usize,
+    target: i128,
+    chosen: &mut Vec<i64>,
+) -> bool {
+    match k {
+        0 => target == 0,
+        1 => {
+            let found = i64::try_from(target)
+                .ok()
+                .filter(|value| positions.get(value).is_some_and(|at| at[0] < end));
+            chosen.extend(found);
+            found.is_some()
+        }
+        k => {
+            for i in (0..end).rev() {
+                chosen.push(seen[i]);
+                if complete(
+                    seen,
+                    positions,
+                    i,
+                    k - 1,
+                    target - i128::from(seen[i]),
+                    chosen,
+                ) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+    }
+}
+
+// Pick `k` more of the sorted `values` from `start` on, adding up to `target`,
+// and hand each combination to `found` with the ones already `chosen` -
+// stopping when it returns false.  Fixes each entry in turn down to the last
+// two, which come from a pass inwards from both ends: O(n^(k-1)).  Sums are
+// i128, so no i64 entries can overflow them.  Returns whether to carry on.
+fn search(
+    values: &[i64],
+    start: usize,
+    k: usize,
+    target: i128,
+    chosen: &mut Vec<i64>,
+    found: &mut dyn FnMut(&[i64]) -> bool,
+) -> bool {
+    match k {
+        0 => target != 0 || found(chosen),
+        1 => {
+            for &value in values[start..].iter().filter(|&&v| i128::from(v) == target) {
+                chosen.push(value);
+                let more = found(chosen);
+                chosen.pop();
+                if !more {
+                    return false;
+                }
+            }
+            true
+        }
+        2 => {
+            if values.len() < start + 2 {
+                return true;
+            }
+            let (mut lo, mut hi) = (start, values.len() - 1);
+            while lo < hi {
+                let (a, b) = (values[lo], values[hi]);
+                match i128::from(a) + i128::from(b) {
+                    sum if sum < target => lo += 1,
+                    sum if sum > target => hi -= 1,
+                    _ => {
+                        // Every pair from the runs of equal values at each end
+                        let pairs = match a == b {
+                            true => (hi - lo + 1) * (hi - lo) / 2,
+                            false => {
+                                let ones = values[lo..].iter().take_while(|&&v| v == a).count();
+                                let twos =
+                                    values[..=hi].iter().rev().take_while(|&&v| v == b).count();
+                                lo += ones;
+                                hi -= twos;
+                                ones * twos
+                            }
+                        };
+                        chosen.extend([a, b].iter());
+                        for _ in 0..pairs {
+                            if !found(chosen) {
+                                chosen.truncate(chosen.len() - 2);
+                                return false;
+                            }
+                        }
+                        chosen.truncate(chosen.len() - 2);
+                        if a == b {
+                            break;
+                        }
+                    }
+                }
+            }
+            true
+        }
+        k => {
+            for i in start..values.len() {
+                // Nothing later can be small enough
+                let least: i128 = values[i..].iter().take(k).map(|&v| i128::from(v)).sum();
+                if i + k > values.len() || least > target {
+                    break;
+                }
+                chosen.push(values[i]);
+                let rest = target - i128::from(values[i]);
+                let more = search(values, i + 1, k - 1, rest, chosen, found);
+                chosen.pop();
+                if !more {
+                    return false;
+                }
+            }
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_errors() {
+        let error = DayOne::parse("1721\n97x").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Expected a number, found '97x'");
+
+        let day = DayOne::parse("1\n2\n3").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::NoSolution(_))));
+        let day = DayOne::parse("1\n2\n3").unwrap();
+        assert!(matches!(day.with_param("k", "0"), Err(Error::Usage(_))));
+
+        // Each entry can only be used once - 10 + 1005 + 1005 doesn't count
+        let day = DayOne::parse("10\n1005\n5").unwrap();
+        assert!(matches!(day.part_two(), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn test_k_sum() {
+        let values = [1721, 979, 366, 299, 675, 1456];
+        assert!(find_k_sum(&values, 2, 2020) == Some(vec![299, 1721]));
+        assert!(find_k_sum(&values, 3, 2020) == Some(vec![366, 675, 979]));
+        assert!(find_k_sum(&values, 4, 2020).is_none());
+        assert!(find_k_sum(&values, 1, 979) == Some(vec![979]));
+        assert!(find_k_sum(&[-5, 3, 10, 2], 2, 5) == Some(vec![-5, 10]));
+
+        // Equal entries are different combinations
+        assert!(all_k_sums(&[1, 1, 1], 2, 2).len() == 3);
+        assert!(all_k_sums(&[1, 1, 2, 2, 3], 2, 3).len() == 4);
+        assert!(
+            all_k_sums(&[1, 2, 3, 4, 5, 6], 3, 10)
+                == vec![vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]]
+        );
+
+        // Sums and products that are too big for an i64: the sums are done
+        // in an i128, and a product that's too big even for that is an error
+        let big = i64::MAX;
+        assert!(find_k_sum(&[big, big, 1], 2, -2).is_none());
+        assert!(product(&[big, big]).unwrap() == i128::from(big) * i128::from(big));
+        let day = DayOne::parse(&format!("{}\n{}\n-{}\n0", big, big, big))
+            .unwrap()
+            .with_param("target", &big.to_string())
+            .unwrap();
+        assert!(day.part_one().unwrap().to_string() == "0");
+        assert!(matches!(day.part_two(), Err(Error::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_stream() {
+        let report = "1721\n979\n366\n299\n675\n1456";
+        let found = |k| stream_k_sum(report.as_bytes(), k, 2020).unwrap();
+        assert!(found(2) == Some(vec![1721, 299]));
+        assert!(found(3) == Some(vec![979, 366, 675]));
+        assert!(found(4).is_none());
+
+        // It stops once it has an answer, so never sees the bad line
+        let report = "1\r\n2\n\n2019\nx";
+        assert!(stream_k_sum(report.as_bytes(), 2, 2020).unwrap() == Some(vec![1, 2019]));
+        let error = stream_k_sum(report.as_bytes(), 2, 5).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 5: Expected a number, found 'x'");
+
+        // A value only counts once, however many times it's needed
+        assert!(stream_k_sum("5\n1\n5".as_bytes(), 2, 10).unwrap() == Some(vec![5, 5]));
+        assert!(stream_k_sum("5\n1\n4".as_bytes(), 2, 10).unwrap().is_none());
+
+        // A long report, with the pair right at the end
+        let mut report: String = (0..200_000).map(|n| format!("{}\n", 2 * n)).collect();
+        report.push_str("3\n");
+        let found = stream_k_sum(report.as_bytes(), 2, 400_001).unwrap();
+        assert!(found == Some(vec![399_998, 3]));
+
+        // From the command line, with the report in a file
+        let path = env::temp_dir().join(format!("aoc-day01-{}.txt", process::id()));
+        fs::write(&path, "1721\n979\n366\n299\n675\n1456\n").unwrap();
+        let day = DayOne::parse("")
+            .unwrap()
+            .with_param("stream", path.to_str().unwrap())
+            .unwrap();
+        assert!(day.part_one().unwrap().to_string() == "514579");
+        assert!(day.part_two().unwrap().to_string() == "241861950");
+        let day = day.with_param("all", "true").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Usage(_))));
+        fs::remove_file(&path).unwrap();
+        let day = DayOne::parse("")
+            .unwrap()
+            .with_param("stream", path.to_str().unwrap());
+        assert!(matches!(day.unwrap().part_one(), Err(Error::Io { .. })));
+    }
+}
@@ -0,0 +1,405 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::{Extra, Part, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    // Example: "8-10 q: qqqqqqqtqq"
+    static ref RECORD: Regex = Regex::new(r"^(\d+)-(\d+) ([a-z]+): (\S+)$").unwrap();
+}
+
+/// One line of the password database, e.g. "1-3 a: abcde".  What the numbers
+/// and letters mean is up to the policy.
+pub struct Record {
+    pub letters: String,
+    pub min: usize,
+    pub max: usize,
+    pub password: String,
+    pub line: usize,
+}
+
+impl Record {
+    pub fn from<'a>(record: impl Into<Text<'a>>) -> Result<Record> {
+        let record = record.into();
+        let cap = record.captures(&RECORD, "'min-max letter: password'")?;
+        let (min, max) = (cap.group(1)?.num()?, cap.group(2)?.num()?);
+        if min == 0 || min > max {
+            return Err(cap.group(1)?.error(format!("Bad range {}-{}", min, max)));
+        }
+
+        Ok(Record {
+            letters: cap.group(3)?.as_str().to_string(),
+            min,
+            max,
+            password: cap.group(4)?.as_str().to_string(),
+            line: record.line(),
+        })
+    }
+
+    // How many of the password's characters are any of the letters
+    fn count(&self) -> usize {
+        self.password
+            .chars()
+            .filter(|&c| self.letters.contains(c))
+            .count()
+    }
+
+    // e.g. "'a'", or "any of 'ab'"
+    fn describe_letters(&self) -> String {
+        match self.letters.chars().count() {
+            1 => format!("'{}'", self.letters),
+            _ => format!("any of '{}'", self.letters),
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.min, self.max, self.letters, self.password
+        )
+    }
+}
+
+/// A rule a record's password has to follow
+pub trait PasswordPolicy {
+    /// Why the password breaks the rule, or `None` if it doesn't
+    fn violation(&self, record: &Record) -> Option<String>;
+
+    fn allows(&self, record: &Record) -> bool {
+        self.violation(record).is_none()
+    }
+}
+
+/// Part one's policy: the letters appear between min and max times
+pub struct Count;
+
+impl PasswordPolicy for Count {
+    fn violation(&self, record: &Record) -> Option<String> {
+        let count = record.count();
+        match (record.min..=record.max).contains(&count) {
+            true => None,
+            false => Some(format!(
+                "{} appears {} times, expected {} to {}",
+                record.describe_letters(),
+                count,
+                record.min,
+                record.max
+            )),
+        }
+    }
+}
+
+/// Part two's policy: exactly one of positions min and max (1-based) holds
+/// one of the letters
+pub struct Positions;
+
+impl PasswordPolicy for Positions {
+    fn violation(&self, record: &Record) -> Option<String> {
+        // Positions may be past the end of the password
+        let chars: Vec<char> = record.password.chars().collect();
+        let at = |i: usize| {
+            chars
+                .get(i - 1)
+                .is_some_and(|&c| record.letters.contains(c))
+        };
+        let letters = record.describe_letters();
+        match (at(record.min), at(record.max)) {
+            (true, true) => Some(format!(
+                "{} is at both positions {} and {}",
+                letters, record.min, record.max
+            )),
+            (false, false) => Some(format!(
+                "{} is at neither position {} nor {}",
+                letters, record.min, record.max
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The letters appear at least min times
+pub struct AtLeast;
+
+impl PasswordPolicy for AtLeast {
+    fn violation(&self, record: &Record) -> Option<String> {
+        let count = record.count();
+        match count >= record.min {
+            true => None,
+            false => Some(format!(
+                "{} appears {} times, expected at least {}",
+                record.describe_letters(),
+                count,
+                record.min
+            )),
+        }
+    }
+}
+
+/// The letters appear at most max times
+pub struct AtMost;
+
+impl PasswordPolicy for AtMost {
+    fn violation(&self, record: &Record) -> Option<String> {
+        let count = record.count();
+        match count <= record.max {
+            true => None,
+            false => Some(format!(
+                "{} appears {} times, expected at most {}",
+                record.describe_letters(),
+                count,
+                record.max
+            )),
+        }
+    }
+}
+
+/// The password matches a regex, whatever the record's numbers and letters
+pub struct Matches(pub Regex);
+
+impl PasswordPolicy for Matches {
+    fn violation(&self, record: &Record) -> Option<String> {
+        match self.0.is_match(&record.password) {
+            true => None,
+            false => Some(format!("'{}' doesn't match /{}/", record.password, self.0)),
+        }
+    }
+}
+
+/// Every one of some policies
+pub struct AllOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for AllOf {
+    fn violation(&self, record: &Record) -> Option<String> {
+        let violations: Vec<String> = self
+            .0
+            .iter()
+            .filter_map(|policy| policy.violation(record))
+            .collect();
+        match violations.is_empty() {
+            true => None,
+            false => Some(violations.join("; ")),
+        }
+    }
+}
+
+/// A policy from its spec: "count", "positions", "at-least", "at-most" or
+/// "regex:<pattern>", or several joined with '&' which must all pass, e.g.
+/// "count&regex:^[a-z]{8,}$".  A regex runs to the end of the spec, '&'s and
+/// all.
+pub fn policy(spec: &str) -> Result<Box<dyn PasswordPolicy>> {
+    let mut policies: Vec<Box<dyn PasswordPolicy>> = Vec::new();
+    let mut rest = spec;
+    loop {
+        if let Some(pattern) = rest.strip_prefix("regex:") {
+            let regex = Regex::new(pattern)
+                .map_err(|e| Error::usage(format!("Bad regex in password policy: {}", e)))?;
+            policies.push(Box::new(Matches(regex)));
+            break;
+        }
+
+        let (name, next) = rest
+            .split_once('&')
+            .map_or((rest, None), |(n, r)| (n, Some(r)));
+        policies.push(match name {
+            "count" => Box::new(Count),
+            "positions" => Box::new(Positions),
+            "at-least" => Box::new(AtLeast),
+            "at-most" => Box::new(AtMost),
+            _ => {
+                return Err(Error::usage(format!(
+                    "Unknown password policy '{}' - expected count, positions, at-least, at-most or regex:<pattern>",
+                    name
+                )))
+            }
+        });
+        match next {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+
+    Ok(match policies.len() {
+        1 => policies.pop().unwrap(),
+        _ => Box::new(AllOf(policies)),
+    })
+}
+
+pub struct DayTwo {
+    records: Vec<Record>,
+    policy: Option<Box<dyn PasswordPolicy>>, // In place of each part's own
+    report: bool,                            // Say why each password fails
+}
+
+impl DayTwo {
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Every record whose password breaks the policy, and why
+    pub fn violations<'a>(&'a self, policy: &dyn PasswordPolicy) -> Vec<(&'a Record, String)> {
+        self.records
+            .iter()
+            .filter_map(|record| policy.violation(record).map(|why| (record, why)))
+            .collect()
+    }
+
+    /// Each password that breaks the part's policy and why, then how many do
+    pub fn report(&self, part: Part) -> String {
+        let violations = self.violations(self.policy(part));
+        let mut lines: Vec<String> = violations
+            .iter()
+            .map(|(record, why)| format!("line {} ({}): {}", record.line, record, why))
+            .collect();
+        lines.push(format!(
+            "{} of {} passwords break the policy",
+            violations.len(),
+            self.records.len()
+        ));
+        lines.join("\n")
+    }
+
+    // The one asked for, or else the part's own
+    fn policy(&self, part: Part) -> &dyn PasswordPolicy {
+        match (&self.policy, part) {
+            (Some(policy), _) => policy.as_ref(),
+            (None, Part::One) => &Count,
+            (None, Part::Two) => &Positions,
+        }
+    }
+
+    fn count_valid(&self, part: Part) -> i32 {
+        let policy = self.policy(part);
+        let valid = self
+            .records
+            .iter()
+            .filter(|r| policy.violation(r).is_none());
+        valid.count() as i32
+    }
+}
+
+impl Solution for DayTwo {
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayTwo {
+            records: Text::new(input).parse_lines(Record::from)?,
+            policy: None,
+            report: false,
+        })
+    }
+
+    // The policy for both parts, as a spec for `policy`, and whether to say
+    // why each password fails
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "policy" => Ok(DayTwo {
+                policy: Some(policy(value)?),
+                ..self
+            }),
+            "report" => Ok(DayTwo {
+                report: crate::parse_bool(name, value)?,
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 2", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<i32> {
+        Ok(self.count_valid(Part::One))
+    }
+
+    fn part_two(&self) -> Result<i32> {
+        Ok(self.count_valid(Part::Two))
+    }
+
+    // Why each password fails
+    fn extras(&self, part: Part) -> Result<Vec<Extra>> {
+        Ok(match self.report {
+            true => vec![Extra::Show(self.report(part))],
+            false => Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayTwo::parse("1-3 a: abcde\n1-3 b cdefg").err().unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+        assert!(DayTwo::parse("3-1 a: abcde").is_err());
+
+        // A position past the end of the password just doesn't match
+        let day = DayTwo::parse("1-9 a: abcde").unwrap();
+        assert!(day.part_two().unwrap() == 1);
+
+        assert!(matches!(policy("count&sometimes"), Err(Error::Usage(_))));
+        assert!(matches!(policy("regex:(a"), Err(Error::Usage(_))));
+    }
+
+    #[test]
+    fn test_policies() {
+        let day = DayTwo::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+        let why = |spec: &str| -> Vec<String> {
+            let policy = policy(spec).unwrap();
+            day.violations(policy.as_ref())
+                .iter()
+                .map(|(record, why)| format!("{}: {}", record.line, why))
+                .collect()
+        };
+
+        assert!(why("count") == ["2: 'b' appears 0 times, expected 1 to 3"]);
+        assert!(
+            why("positions")
+                == [
+                    "2: 'b' is at neither position 1 nor 3",
+                    "3: 'c' is at both positions 2 and 9"
+                ]
+        );
+        assert!(why("at-most").is_empty());
+        assert!(why("regex:^[a-e]+$") == ["2: 'cdefg' doesn't match /^[a-e]+$/"]);
+        assert!(
+            why("at-least&regex:^.{5}$")
+                == [
+                    "2: 'b' appears 0 times, expected at least 1",
+                    "3: 'ccccccccc' doesn't match /^.{5}$/"
+                ]
+        );
+
+        // Any of several letters
+        let day = DayTwo::parse("1-2 ab: abc").unwrap();
+        assert!(day.part_one().unwrap() == 1 && day.part_two().unwrap() == 0);
+        let record = &day.records()[0];
+        assert!(Positions.violation(record).unwrap() == "any of 'ab' is at both positions 1 and 2");
+        assert!(record.to_string() == "1-2 ab: abc");
+    }
+
+    #[test]
+    fn test_report() {
+        let day = DayTwo::parse("1-3 a: abcde\n1-3 b: cdefg").unwrap();
+        assert!(day.extras(Part::One).unwrap().is_empty());
+        let day = day.with_param("report", "true").unwrap();
+        assert!(
+            day.extras(Part::One).unwrap()
+                == vec![Extra::Show(
+                    "line 2 (1-3 b: cdefg): 'b' appears 0 times, expected 1 to 3\n\
+                     1 of 2 passwords break the policy"
+                        .to_string()
+                )]
+        );
+        assert!(day
+            .report(Part::Two)
+            .ends_with("\n1 of 2 passwords break the policy"));
+        let day = day.with_param("policy", "at-most").unwrap();
+        assert!(day.report(Part::Two) == "0 of 2 passwords break the policy");
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Point, Wrap};
+use crate::parse::Text;
+use crate::solution::{Extra, Part, Solution};
+
+/// How far right and down each step goes
+pub type Slope = (isize, isize);
+
+/// One kind of square on the map, and what landing on it costs
+#[derive(Clone)]
+pub struct Terrain {
+    pub symbol: char,
+    pub name: String,
+    pub cost: u64,
+}
+
+/// What each symbol on the map means.  Without one in the input, it's just
+/// trees ('#', costing 1) and open ground ('.', free), so a path's cost is
+/// the trees it hits.
+pub struct Legend(pub Vec<Terrain>);
+
+impl Legend {
+    fn position(&self, symbol: char) -> Option<usize> {
+        self.0.iter().position(|terrain| terrain.symbol == symbol)
+    }
+
+    // One terrain per line, e.g. "^ rock 5".  Each adds to (or replaces one
+    // of) the usual trees and open ground.
+    fn parse(legend: Text) -> Result<Legend> {
+        let mut terrains = Legend::default().0;
+        for line in legend.non_empty_lines() {
+            let terrain = match line.words()[..] {
+                [symbol, name, cost] => {
+                    let mut chars = symbol.as_str().chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(symbol), None) => Terrain {
+                            symbol,
+                            name: name.as_str().to_string(),
+                            cost: cost.num()?,
+                        },
+                        _ => return Err(symbol.error("Expected a single char")),
+                    }
+                }
+                _ => return Err(line.error("Expected 'symbol name cost'")),
+            };
+
+            match terrains.iter().position(|t| t.symbol == terrain.symbol) {
+                Some(i) => terrains[i] = terrain,
+                None => terrains.push(terrain),
+            }
+        }
+        Ok(Legend(terrains))
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Legend {
+        let terrain = |symbol, name: &str, cost| Terrain {
+            symbol,
+            name: name.to_string(),
+            cost,
+        };
+        Legend(vec![terrain('#', "tree", 1), terrain('.', "open", 0)])
+    }
+}
+
+/// How many of each terrain a path lands on, and what they cost, in the
+/// legend's order
+pub struct Breakdown(pub Vec<(Terrain, u64)>);
+
+impl Breakdown {
+    /// The path's cost, or an error if it's too big to hold
+    pub fn total(&self) -> Result<u64> {
+        self.0
+            .iter()
+            .try_fold(0u64, |total, (terrain, count)| {
+                total.checked_add(terrain.cost.checked_mul(*count)?)
+            })
+            .ok_or_else(|| Error::unsupported("the path's cost is too big"))
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .0
+            .iter()
+            .map(|(terrain, count)| match terrain.cost.checked_mul(*count) {
+                Some(cost) => format!("{} {} (cost {})", count, terrain.name, cost),
+                None => format!("{} {} (cost too big)", count, terrain.name),
+            })
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+pub struct DayThree {
+    legend: Legend,
+    // Each square's terrain, by its place in the legend.  The pattern repeats
+    // to the right.
+    map: Grid<usize>,
+    slope: Slope,          // Part one's
+    search: Option<Slope>, // Part one tries every slope up to this far instead
+    render: bool,          // Draw part one's path
+}
+
+impl Solution for DayThree {
+    type PartOne = Answer;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        // The map, optionally after a legend and a blank line
+        let (legend, map) = match Text::new(input).blocks()[..] {
+            [map] => (Legend::default(), map),
+            [legend, map] => (Legend::parse(legend)?, map),
+            ref blocks => {
+                return Err(Error::parse(format!(
+                    "Expected a map, maybe after a legend, found {} blocks of lines",
+                    blocks.len()
+                )))
+            }
+        };
+        let map = map.grid(|c| legend.position(c))?;
+
+        Ok(DayThree {
+            legend,
+            map: map.with_wrap(Wrap::Horizontal),
+            slope: (3, 1),
+            search: None,
+            render: false,
+        })
+    }
+
+    // Part one's slope as "right,down", the furthest right and down to search,
+    // and whether to draw the path
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "slope" => Ok(DayThree {
+                slope: parse_slope(value)?,
+                ..self
+            }),
+            "search" => Ok(DayThree {
+                search: Some(parse_slope(value)?),
+                ..self
+            }),
+            "render" => Ok(DayThree {
+                render: crate::parse_bool(name, value)?,
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 3", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        if let Some(furthest) = self.search {
+            return self.search(furthest);
+        }
+
+        let breakdown = self.breakdown(self.slope);
+        verbose!("{}", breakdown);
+        Ok(Answer::Cost(breakdown.total()?))
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+        slopes.iter().try_fold(1u64, |acc, &slope| {
+            acc.checked_mul(self.breakdown(slope).total()?)
+                .ok_or_else(|| Error::unsupported("the product of the paths' costs is too big"))
+        })
+    }
+
+    // Part one's path drawn on the map
+    fn extras(&self, part: Part) -> Result<Vec<Extra>> {
+        Ok(match (part, self.render, self.search) {
+            (Part::One, true, None) => vec![Extra::Show(self.render(self.slope))],
+            _ => Vec::new(),
+        })
+    }
+}
+
+impl DayThree {
+    // Every point the path visits, from the top left to the bottom
+    fn path(&self, (right, down): Slope) -> impl Iterator<Item = Point> {
+        let height = self.map.height() as isize;
+        (0..)
+            .map(move |i| (i * right, i * down))
+            .take_while(move |&(_, y)| y < height)
+    }
+
+    // The terrain's place in the legend.  Wrapping means every point on a
+    // path is on the map.
+    fn square(&self, point: Point) -> usize {
+        *self.map.get(point).unwrap()
+    }
+
+    fn terrain(&self, point: Point) -> &Terrain {
+        &self.legend.0[self.square(point)]
+    }
+
+    /// What the path down `slope` lands on
+    pub fn breakdown(&self, slope: Slope) -> Breakdown {
+        let mut counts = vec![0; self.legend.0.len()];
+        for point in self.path(slope) {
+            counts[self.square(point)] += 1;
+        }
+        Breakdown(self.legend.0.iter().cloned().zip(counts).collect())
+    }
+
+    /// The slopes costing the least and most, of every different slope up to
+    /// `furthest` right and down per step (so 2,2 isn't tried, as it's 1,1's
+    /// direction).  Ties go to the first, by down then right.
+    pub fn search(&self, (furthest_right, furthest_down): Slope) -> Result<Answer> {
+        let slopes = (1..=furthest_down)
+            .flat_map(|down| (0..=furthest_right).map(move |right| (right, down)))
+            .filter(|&(right, down)| gcd(right, down) == 1)
+            .map(|slope| Ok((slope, self.breakdown(slope).total()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // There's always 0,1 (straight down)
+        let mut slopes = slopes.into_iter();
+        let first = slopes.next().unwrap();
+        let (cheapest, dearest) = slopes.fold((first, first), |(cheapest, dearest), slope| {
+            (
+                if slope.1 < cheapest.1 {
+                    slope
+                } else {
+                    cheapest
+                },
+                if slope.1 > dearest.1 { slope } else { dearest },
+            )
+        });
+        Ok(Answer::Search { cheapest, dearest })
+    }
+
+    /// The map with the path down `slope` on it: 'O' where it's free and 'X'
+    /// where it costs, with the map repeated to the right as far as the path
+    /// goes.
+    pub fn render(&self, slope: Slope) -> String {
+        let path: HashSet<Point> = self.path(slope).collect();
+        let width = self.map.width() as isize;
+        let furthest = path.iter().map(|&(x, _)| x).max().unwrap_or(0);
+        let repeats = furthest / width + 1;
+
+        let rows: Vec<String> = (0..self.map.height() as isize)
+            .map(|y| {
+                (0..width * repeats)
+                    .map(|x| {
+                        let terrain = self.terrain((x, y));
+                        match (path.contains(&(x, y)), terrain.cost) {
+                            (true, 0) => 'O',
+                            (true, _) => 'X',
+                            (false, _) => terrain.symbol,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+}
+
+/// Part one's answer: the cost of one slope, or the results of a search
+pub enum Answer {
+    Cost(u64),
+    Search {
+        cheapest: (Slope, u64),
+        dearest: (Slope, u64),
+    },
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Cost(cost) => write!(f, "{}", cost),
+            Answer::Search {
+                cheapest: ((r1, d1), c1),
+                dearest: ((r2, d2), c2),
+            } => write!(
+                f,
+                "cheapest {},{} (cost {}), dearest {},{} (cost {})",
+                r1, d1, c1, r2, d2, c2
+            ),
+        }
+    }
+}
+
+// e.g. "3,1" - at least one down, so the path gets to the bottom
+fn parse_slope(value: &str) -> Result<Slope> {
+    let slope = value
+        .split_once(',')
+        .and_then(|(right, down)| Some((right.parse().ok()?, down.parse().ok()?)))
+        .filter(|&(right, down)| right >= 0 && down >= 1);
+    slope.ok_or_else(|| {
+        Error::usage(format!(
+            "Expected 'right,down' (right >= 0, down >= 1), found '{}'",
+            value
+        ))
+    })
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayThree::parse("..#\n.x.").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 2: Unexpected char: 'x'");
+        assert!(DayThree::parse("..#\n..").is_err());
+
+        let day = DayThree::parse("..#").unwrap();
+        assert!(matches!(
+            day.with_param("slope", "1,0"),
+            Err(Error::Usage(_))
+        ));
+
+        let error = DayThree::parse("^ rock 5\n~ ice\n\n.^~").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Expected 'symbol name cost'");
+        let error = DayThree::parse("^ rock 5\n\n.^\n.~").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 4, column 2: Unexpected char: '~'");
+
+        // Costs that are too big to add up or multiply
+        let day = DayThree::parse("^ rock 18446744073709551615\n\n^\n^").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+        assert!(day
+            .breakdown((0, 1))
+            .to_string()
+            .ends_with("2 rock (cost too big)"));
+        let day = DayThree::parse("^ rock 4294967296\n\n^").unwrap();
+        assert!(day.part_one().unwrap().to_string() == "4294967296");
+        assert!(matches!(day.part_two(), Err(Error::Unsupported(_))));
+        let day = day.with_param("search", "1,1").unwrap();
+        assert!(day.part_one().is_ok());
+    }
+
+    #[test]
+    fn test_terrain() {
+        let day = DayThree::parse("^ rock 5\n~ ice 0\n# tree 2\n\n.#~\n^~.\n.#^").unwrap();
+        let breakdown = day.breakdown((1, 1));
+        assert!(
+            breakdown.to_string()
+                == "0 tree (cost 0), 1 open (cost 0), 1 rock (cost 5), 1 ice (cost 0)"
+        );
+        assert!(breakdown.total().unwrap() == 5);
+        assert!(day.breakdown((0, 1)).total().unwrap() == 5);
+        assert!(day.breakdown((2, 1)).total().unwrap() == 2);
+        assert!(day.render((1, 1)) == "O#~\n^O.\n.#X");
+    }
+
+    #[test]
+    fn test_render() {
+        let day = DayThree::parse("..#\n#..\n.#.").unwrap();
+        assert!(day.render((1, 1)) == "O.#\n#O.\n.#O");
+        assert!(day.render((2, 1)) == "O.#..#\n#.O#..\n.#..X.");
+        assert!(day.render((0, 2)) == "O.#\n#..\nO#.");
+
+        // Only drawn when asked for, and only for part one
+        assert!(day.extras(Part::One).unwrap().is_empty());
+        let day = day.with_param("render", "true").unwrap();
+        assert!(day.extras(Part::One).unwrap() == vec![Extra::Show(day.render((3, 1)))]);
+        assert!(day.extras(Part::Two).unwrap().is_empty());
+    }
+}
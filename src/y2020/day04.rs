@@ -0,0 +1,831 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::output::{csv_field, json_string};
+use crate::parse::Text;
+use crate::solution::{Extra, Part, Solution};
+use regex::Regex;
+
+// The puzzle's rules, as a schema (see `Schema::parse`).  Built in, so it
+// doesn't matter where the inputs are.
+const PASSPORT_SCHEMA: &str = include_str!("../../data/2020/4_passport_schema.txt");
+
+pub struct DayFour {
+    passports: Vec<Passport>,
+    schema: Schema,
+    report: bool,                     // Say what's wrong with each document in part two
+    export: Option<(Export, String)>, // Where to write the documents out
+}
+
+impl DayFour {
+    pub fn passports(&self) -> &[Passport] {
+        &self.passports
+    }
+
+    /// What's wrong with each invalid document, then how often each field
+    /// failed and why
+    pub fn report(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        let mut failures: HashMap<String, HashMap<&str, usize>> = HashMap::new();
+        for (i, passport) in self.passports.iter().enumerate() {
+            let validation = self.schema.validate(passport);
+            if validation.is_valid() {
+                continue;
+            }
+
+            lines.push(format!(
+                "Document {} (line {}): {}",
+                i + 1,
+                passport.line,
+                validation
+            ));
+            for (field, problem) in &validation.0 {
+                *failures
+                    .entry(field.clone())
+                    .or_default()
+                    .entry(problem.kind())
+                    .or_default() += 1;
+            }
+        }
+
+        let invalid = lines.len();
+        lines.push(format!(
+            "{} of {} documents invalid",
+            invalid,
+            self.passports.len()
+        ));
+        // In the schema's order
+        for field in &self.schema.0 {
+            if let Some(kinds) = failures.get(field.name.as_str()) {
+                let mut kinds: Vec<(&str, usize)> =
+                    kinds.iter().map(|(&kind, &n)| (kind, n)).collect();
+                kinds.sort_unstable();
+                let total: usize = kinds.iter().map(|(_, n)| n).sum();
+                let kinds: Vec<String> = kinds
+                    .iter()
+                    .map(|(kind, n)| format!("{} {}", n, kind))
+                    .collect();
+                lines.push(format!(
+                    "  {}: {} failed ({})",
+                    field.name,
+                    total,
+                    kinds.join(", ")
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Every document as CSV: where it starts, whether it's complete and
+    /// valid, what's wrong with it, and then a column per key - the schema's,
+    /// then any others in the order they first appear.  A repeated key has
+    /// its values separated by spaces.
+    pub fn to_csv(&self) -> String {
+        let mut keys: Vec<&str> = self.schema.0.iter().map(|f| f.name.as_str()).collect();
+        for entry in self.passports.iter().flat_map(|p| &p.entries) {
+            if !keys.contains(&entry.key.as_str()) {
+                keys.push(&entry.key);
+            }
+        }
+
+        let mut header = vec!["document", "line", "complete", "valid", "problems"];
+        header.extend(&keys);
+        let mut rows = vec![header.iter().map(|key| csv_field(key)).collect::<Vec<_>>()];
+        for (i, passport) in self.passports.iter().enumerate() {
+            let validation = self.schema.validate(passport);
+            let mut row = vec![
+                (i + 1).to_string(),
+                passport.line.to_string(),
+                self.schema.complete(passport).to_string(),
+                validation.is_valid().to_string(),
+                csv_field(&validation.to_string()),
+            ];
+            for key in &keys {
+                let values: Vec<&str> = passport.values(key).collect();
+                row.push(csv_field(&values.join(" ")));
+            }
+            rows.push(row);
+        }
+
+        let rows: Vec<String> = rows.iter().map(|row| row.join(",") + "\n").collect();
+        rows.concat()
+    }
+
+    /// Every document as a JSON array, one object per line, with each
+    /// key:value pair in order and where it was
+    pub fn to_json(&self) -> String {
+        let documents: Vec<String> = self
+            .passports
+            .iter()
+            .map(|passport| {
+                let validation = self.schema.validate(passport);
+                let problems: Vec<String> = validation
+                    .0
+                    .iter()
+                    .map(|(field, problem)| {
+                        format!(
+                            "{{\"field\":{},\"kind\":{},\"message\":{}}}",
+                            json_string(field),
+                            json_string(problem.kind()),
+                            json_string(&problem.to_string())
+                        )
+                    })
+                    .collect();
+                let entries: Vec<String> = passport
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        format!(
+                            "{{\"key\":{},\"value\":{},\"line\":{},\"column\":{}}}",
+                            json_string(&entry.key),
+                            json_string(&entry.value),
+                            entry.line,
+                            entry.column
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{{\"line\":{},\"complete\":{},\"valid\":{},\"problems\":[{}],\"fields\":[{}]}}",
+                    passport.line,
+                    self.schema.complete(passport),
+                    validation.is_valid(),
+                    problems.join(","),
+                    entries.join(",")
+                )
+            })
+            .collect();
+
+        match documents.is_empty() {
+            true => "[]\n".to_string(),
+            false => format!("[\n{}\n]\n", documents.join(",\n")),
+        }
+    }
+
+    /// Every document as CSV or JSON
+    pub fn export(&self, format: Export) -> String {
+        match format {
+            Export::Csv => self.to_csv(),
+            Export::Json => self.to_json(),
+        }
+    }
+}
+
+/// What documents can be exported as
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Export {
+    Csv,
+    Json,
+}
+
+impl Export {
+    // From the file's extension
+    fn from_filename(filename: &str) -> Option<Export> {
+        match Path::new(filename).extension()?.to_str()? {
+            "csv" => Some(Export::Csv),
+            "json" => Some(Export::Json),
+            _ => None,
+        }
+    }
+}
+
+impl Solution for DayFour {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let passports: Vec<Passport> = Text::new(input)
+            .blocks()
+            .into_iter()
+            .map(Passport::new)
+            .collect::<Result<_>>()?;
+
+        Ok(DayFour {
+            passports,
+            schema: Schema::passport(),
+            report: false,
+            export: None,
+        })
+    }
+
+    // A file with the schema to check documents against, in place of the
+    // puzzle's passport rules, whether to report what's wrong with them, and
+    // a .csv or .json file to export them to
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "schema" => Ok(DayFour {
+                schema: Schema::parse(crate::read_file(value)?.as_str())?,
+                ..self
+            }),
+            "report" => Ok(DayFour {
+                report: crate::parse_bool(name, value)?,
+                ..self
+            }),
+            "export" => match Export::from_filename(value) {
+                Some(format) => Ok(DayFour {
+                    export: Some((format, value.to_string())),
+                    ..self
+                }),
+                None => Err(Error::usage(format!(
+                    "export needs a .csv or .json file, not '{}'",
+                    value
+                ))),
+            },
+            _ => Err(Error::usage(format!("No parameter '{}' for day 4", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        Ok(self
+            .passports
+            .iter()
+            .filter(|x| self.schema.complete(x))
+            .count())
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(self
+            .passports
+            .iter()
+            .filter(|x| self.schema.valid(x))
+            .count())
+    }
+
+    // What's wrong with part two's documents, and the file to write them to
+    fn extras(&self, part: Part) -> Result<Vec<Extra>> {
+        let mut extras: Vec<Extra> = Vec::new();
+        if part == Part::Two {
+            if self.report {
+                extras.push(Extra::Show(self.report()));
+            }
+            if let Some((format, path)) = &self.export {
+                extras.push(Extra::File {
+                    path: path.to_string(),
+                    contents: self.export(*format),
+                });
+            }
+        }
+        Ok(extras)
+    }
+}
+
+/// One "key:value" pair, and where it starts in the input
+pub struct Entry {
+    pub key: String,
+    pub value: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Every field of one passport (or any other document), whatever they're
+/// called, in the order they're written - repeats and all
+pub struct Passport {
+    pub entries: Vec<Entry>,
+    pub line: usize, // Where it starts in the input
+}
+
+impl Passport {
+    pub fn new<'a>(definition: impl Into<Text<'a>>) -> Result<Passport> {
+        let definition = definition.into();
+        let entries = definition
+            .fields(":")?
+            .into_iter()
+            .map(|(key, value)| Entry {
+                key: key.as_str().to_string(),
+                value: value.as_str().to_string(),
+                line: key.line(),
+                column: key.column().unwrap_or(1),
+            })
+            .collect();
+
+        Ok(Passport {
+            entries,
+            line: definition.line(),
+        })
+    }
+
+    /// A field's value - the last one, if it's there more than once
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.key == field)
+            .map(|entry| entry.value.as_str())
+    }
+
+    /// Every value of a field, in order
+    pub fn values<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a str> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.key == field)
+            .map(|entry| entry.value.as_str())
+    }
+}
+
+/// What a field's value has to look like
+pub enum Validator {
+    /// Anything goes
+    Any,
+    /// A whole number in a range, e.g. "int 1920-2002"
+    Int(i64, i64),
+    /// e.g. "regex ^[0-9]{9}$" - the pattern runs to the end of the line
+    Regex(Regex),
+    /// One of a list of words, e.g. "one-of amb blu"
+    OneOf(Vec<String>),
+    /// A whole number followed by a unit, in that unit's range, e.g.
+    /// "units cm:150-193 in:59-76"
+    Units(Vec<(String, i64, i64)>),
+}
+
+impl Validator {
+    pub fn check(&self, value: &str) -> bool {
+        self.validate(value).is_ok()
+    }
+
+    /// What's wrong with the value, if anything
+    pub fn validate(&self, value: &str) -> std::result::Result<(), Problem> {
+        let bad_format = |expected: String| Problem::BadFormat {
+            value: value.to_string(),
+            expected,
+        };
+
+        match self {
+            Validator::Any => Ok(()),
+            Validator::Int(min, max) => {
+                let n: i64 = value
+                    .parse()
+                    .map_err(|_| bad_format("a whole number".to_string()))?;
+                in_range(value, n, *min, *max, "")
+            }
+            Validator::Regex(regex) => match regex.is_match(value) {
+                true => Ok(()),
+                false => Err(bad_format(format!("/{}/", regex))),
+            },
+            Validator::OneOf(words) => match words.iter().any(|word| word == value) {
+                true => Ok(()),
+                false => Err(bad_format(format!("one of {}", words.join(" ")))),
+            },
+            Validator::Units(units) => {
+                let names: Vec<&str> = units.iter().map(|(name, _, _)| name.as_str()).collect();
+                // The number can be negative, as the range can
+                let sign = usize::from(value.starts_with('-'));
+                let split = value[sign..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .map_or(value.len(), |i| sign + i);
+                let (number, unit) = value.split_at(split);
+                let n: i64 = match (number.parse(), unit.is_empty()) {
+                    (Ok(n), false) => n,
+                    _ => {
+                        return Err(bad_format(format!(
+                            "a whole number then {}",
+                            names.join(" or ")
+                        )))
+                    }
+                };
+                match units.iter().find(|(name, _, _)| name == unit) {
+                    Some((_, min, max)) => in_range(value, n, *min, *max, unit),
+                    None => Err(Problem::UnknownUnit {
+                        unit: unit.to_string(),
+                        expected: names.join(" or "),
+                    }),
+                }
+            }
+        }
+    }
+
+    // The validator's name and whatever follows it on the line
+    fn parse(kind: Text, args: Text) -> Result<Validator> {
+        let range = |range: Text| -> Result<(i64, i64)> {
+            // The min can be negative, so it's the first '-' after its start
+            let sign = usize::from(range.as_str().starts_with('-'));
+            let (min, max) = range
+                .split_at(sign)
+                .1
+                .split_once("-")
+                .map(|(min, max)| (range.split_at(sign + min.as_str().len()).0, max))
+                .ok_or_else(|| range.error("Expected a range 'min-max'"))?;
+            let (min, max): (i64, i64) = (min.num()?, max.num()?);
+            match min <= max {
+                true => Ok((min, max)),
+                false => Err(range.error(format!("Empty range: {} is more than {}", min, max))),
+            }
+        };
+
+        match kind.as_str() {
+            "int" => {
+                let (min, max) = range(args.trim())?;
+                Ok(Validator::Int(min, max))
+            }
+            "regex" => Regex::new(args.trim().as_str())
+                .map(Validator::Regex)
+                .map_err(|e| args.error(format!("Bad regex: {}", e))),
+            "one-of" => Ok(Validator::OneOf(
+                args.words()
+                    .iter()
+                    .map(|word| word.as_str().to_string())
+                    .collect(),
+            )),
+            "units" => args
+                .fields(":")?
+                .into_iter()
+                .map(|(unit, limits)| {
+                    let (min, max) = range(limits)?;
+                    Ok((unit.as_str().to_string(), min, max))
+                })
+                .collect::<Result<_>>()
+                .map(Validator::Units),
+            _ => Err(kind.error(format!(
+                "Unknown validator '{}' - expected int, regex, one-of or units",
+                kind.as_str()
+            ))),
+        }
+    }
+}
+
+// The number `n` (parsed from `value`) is within min-max of its unit
+fn in_range(
+    value: &str,
+    n: i64,
+    min: i64,
+    max: i64,
+    unit: &str,
+) -> std::result::Result<(), Problem> {
+    match (min..=max).contains(&n) {
+        true => Ok(()),
+        false => Err(Problem::OutOfRange {
+            value: value.to_string(),
+            range: format!("{}-{}{}", min, max, unit),
+        }),
+    }
+}
+
+/// Why a field fails its check
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    Missing,
+    OutOfRange { value: String, range: String },
+    BadFormat { value: String, expected: String },
+    UnknownUnit { unit: String, expected: String },
+}
+
+impl Problem {
+    /// e.g. "missing" - for counting the failures of each sort
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Problem::Missing => "missing",
+            Problem::OutOfRange { .. } => "out of range",
+            Problem::BadFormat { .. } => "bad format",
+            Problem::UnknownUnit { .. } => "unknown unit",
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Missing => write!(f, "missing"),
+            Problem::OutOfRange { value, range } => {
+                write!(f, "'{}' is out of range {}", value, range)
+            }
+            Problem::BadFormat { value, expected } => {
+                write!(f, "'{}' is badly formatted, expected {}", value, expected)
+            }
+            Problem::UnknownUnit { unit, expected } => {
+                write!(f, "unknown unit '{}', expected {}", unit, expected)
+            }
+        }
+    }
+}
+
+/// Every field of a document which fails, in the schema's order, and why
+pub struct Validation(pub Vec<(String, Problem)>);
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures: Vec<String> = self
+            .0
+            .iter()
+            .map(|(field, problem)| format!("{} {}", field, problem))
+            .collect();
+        write!(f, "{}", failures.join("; "))
+    }
+}
+
+/// One field a document may have
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub validator: Validator,
+}
+
+/// The fields a kind of document has, and the rules for their values
+pub struct Schema(pub Vec<Field>);
+
+impl Schema {
+    /// The puzzle's passports
+    pub fn passport() -> Schema {
+        Schema::parse(PASSPORT_SCHEMA).unwrap()
+    }
+
+    /// One field per line: its name, "required" or "optional", and then
+    /// optionally how to check its value, e.g. "byr required int 1920-2002".
+    /// Lines starting with '#' are comments.
+    pub fn parse<'a>(schema: impl Into<Text<'a>>) -> Result<Schema> {
+        let mut fields: Vec<Field> = Vec::new();
+        for line in schema.into().non_empty_lines() {
+            let line = line.trim();
+            if line.as_str().starts_with('#') {
+                continue;
+            }
+
+            // Each piece is the text up to the next space, or the rest
+            let next = |text: Text<'a>| {
+                let text = text.trim();
+                text.split_once(" ")
+                    .unwrap_or_else(|| text.split_at(text.as_str().len()))
+            };
+            let (name, rest) = next(line);
+            let (flag, rest) = next(rest);
+            let required = match flag.as_str() {
+                "required" => true,
+                "optional" => false,
+                _ => return Err(flag.error("Expected 'required' or 'optional'")),
+            };
+            let validator = match next(rest) {
+                (kind, _) if kind.is_empty() => Validator::Any,
+                (kind, args) => Validator::parse(kind, args)?,
+            };
+
+            if fields.iter().any(|field| field.name == name.as_str()) {
+                return Err(name.error(format!("Field '{}' is already defined", name.as_str())));
+            }
+            fields.push(Field {
+                name: name.as_str().to_string(),
+                required,
+                validator,
+            });
+        }
+        Ok(Schema(fields))
+    }
+
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.0.iter().find(|field| field.name == name)
+    }
+
+    /// What's wrong with each field of the document: missing if it's
+    /// required, or failing its check if it's there
+    pub fn validate(&self, document: &Passport) -> Validation {
+        let problems = self.0.iter().filter_map(|field| {
+            let problem = match document.get(&field.name) {
+                Some(value) => field.validator.validate(value).err(),
+                None if field.required => Some(Problem::Missing),
+                None => None,
+            };
+            problem.map(|problem| (field.name.clone(), problem))
+        });
+        Validation(problems.collect())
+    }
+
+    /// Are all the required fields present (even if not valid)?
+    pub fn complete(&self, document: &Passport) -> bool {
+        self.validate(document)
+            .0
+            .iter()
+            .all(|(_, problem)| *problem != Problem::Missing)
+    }
+
+    /// Complete, and every field it has passes its check
+    pub fn valid(&self, document: &Passport) -> bool {
+        self.validate(document).is_valid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks a value against the puzzle's rules for a field
+    fn check(field: &str, value: &str) -> bool {
+        Schema::passport()
+            .field(field)
+            .unwrap()
+            .validator
+            .check(value)
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(DayFour::parse("byr:1920 iyr2010").is_err());
+        let error = DayFour::parse("byr:1920\n\nhgt:60in\niyr:2010 eyr")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 4, column 10: Expected 'key:value', found 'eyr'"
+        );
+
+        let error = Schema::parse("byr required\nhgt required units cm:150-x")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 2, column 27: Expected a number, found 'x'"
+        );
+        let error = Schema::parse("byr sometimes").err().unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 1, column 5: Expected 'required' or 'optional'"
+        );
+        assert!(Schema::parse("byr required date").is_err());
+        assert!(Schema::parse("byr required regex (").is_err());
+        assert!(Schema::parse("byr required\nbyr optional").is_err());
+    }
+
+    #[test]
+    fn test_validation() {
+        let problem = |field: &str, value: &str| {
+            let schema = Schema::passport();
+            let problem = schema.field(field).unwrap().validator.validate(value);
+            problem.err().unwrap().to_string()
+        };
+        assert!(problem("byr", "1919") == "'1919' is out of range 1920-2002");
+        assert!(problem("byr", "19x9") == "'19x9' is badly formatted, expected a whole number");
+        assert!(problem("hgt", "200cm") == "'200cm' is out of range 150-193cm");
+        assert!(problem("hgt", "6ft") == "unknown unit 'ft', expected cm or in");
+        assert!(
+            problem("hgt", "cm")
+                == "'cm' is badly formatted, expected a whole number then cm or in"
+        );
+
+        let day = DayFour::parse("byr:1919 hgt:6ft\n\nbyr:1920\n\nbyr:2020 hgt:70").unwrap();
+        let validation = Schema::passport().validate(&day.passports()[0]);
+        assert!(matches!(&validation.0[0], (field, Problem::OutOfRange { .. }) if field == "byr"));
+        assert!(validation.0[1].1 == Problem::Missing);
+        let report = day.report();
+        let report: Vec<&str> = report.lines().collect();
+        assert!(report[1].starts_with("Document 2 (line 3): iyr missing; eyr missing;"));
+        assert!(report[3] == "3 of 3 documents invalid");
+        assert!(report[4] == "  byr: 2 failed (2 out of range)");
+        assert!(report[5] == "  iyr: 3 failed (3 missing)");
+        assert!(report[7] == "  hgt: 3 failed (1 bad format, 1 missing, 1 unknown unit)");
+
+        // Shown for part two, when asked for
+        assert!(day.extras(Part::Two).unwrap().is_empty());
+        let day = day.with_param("report", "true").unwrap();
+        assert!(day.extras(Part::Two).unwrap() == vec![Extra::Show(day.report())]);
+        assert!(day.extras(Part::One).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export() {
+        let day = DayFour::parse("pid:1 x:a\n  x:\"b,c\"\n\nbyr:2000").unwrap();
+        let passport = &day.passports()[0];
+        let keys: Vec<(&str, usize, usize)> = passport
+            .entries
+            .iter()
+            .map(|entry| (entry.key.as_str(), entry.line, entry.column))
+            .collect();
+        assert!(keys == [("pid", 1, 1), ("x", 1, 7), ("x", 2, 3)]);
+        assert!(passport.get("x") == Some("\"b,c\""));
+
+        let csv = day.to_csv();
+        let csv: Vec<&str> = csv.lines().collect();
+        assert!(
+            csv[0] == "document,line,complete,valid,problems,byr,iyr,eyr,hgt,hcl,ecl,pid,cid,x"
+        );
+        assert!(csv[1].starts_with("1,1,false,false,\"byr missing;"));
+        assert!(csv[1].ends_with(",,1,,\"a \"\"b,c\"\"\""));
+        assert!(csv[2].ends_with(",2000,,,,,,,,"));
+
+        let json = day.to_json();
+        assert!(json.starts_with("[\n{\"line\":1,\"complete\":false,\"valid\":false,\"problems\":[{\"field\":\"byr\",\"kind\":\"missing\",\"message\":\"missing\"},"));
+        assert!(json.contains(
+            "{\"key\":\"x\",\"value\":\"\\\"b,c\\\"\",\"line\":2,\"column\":3}]},\n{\"line\":4,"
+        ));
+        assert!(DayFour::parse("").unwrap().to_json() == "[]\n");
+
+        // Solving doesn't write the file - the command line does, from part
+        // two's extras
+        let day = day.with_param("export", "aoc-day04-out.json").unwrap();
+        assert!(day.part_two().is_ok());
+        assert!(!Path::new("aoc-day04-out.json").exists());
+        let file = Extra::File {
+            path: "aoc-day04-out.json".to_string(),
+            contents: day.to_json(),
+        };
+        assert!(day.extras(Part::Two).unwrap() == vec![file]);
+        assert!(day.extras(Part::One).unwrap().is_empty());
+
+        assert!(matches!(
+            day.with_param("export", "out.txt"),
+            Err(Error::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Schema::parse(
+            "# A library card\nnum required regex ^L[0-9]+$\nage optional int 5-120\nnote optional",
+        )
+        .unwrap();
+        let card = |fields: &str| Passport::new(fields).unwrap();
+
+        assert!(schema.valid(&card("num:L123 note:hello")));
+        assert!(schema.valid(&card("num:L123 age:30 extra:ignored")));
+        assert!(!schema.complete(&card("age:30")));
+        assert!(!schema.valid(&card("num:123")));
+        assert!(!schema.valid(&card("num:L123 age:4")));
+
+        // Negative bounds
+        let schema =
+            Schema::parse("t required int -5-5\nd required units c:-10--5 f:14-23").unwrap();
+        assert!(schema.valid(&card("t:-5 d:-7c")));
+        assert!(schema.valid(&card("t:5 d:20f")));
+        assert!(!schema.valid(&card("t:-6 d:-7c")));
+        assert!(!schema.valid(&card("t:0 d:-4c")));
+        assert!(Schema::parse("t required int -5").is_err());
+        let error = Schema::parse("t required int 5--3").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 1, column 16: Empty range: 5 is more than -3"
+        );
+        let error = Schema::parse("t optional\nd required units c:1-2 f:9-8")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 2, column 26: Empty range: 9 is more than 8"
+        );
+    }
+
+    #[test]
+    fn test_byr() {
+        assert!(check("byr", "1920"));
+        assert!(check("byr", "2002"));
+        assert!(!check("byr", "1919"));
+        assert!(!check("byr", "2003"));
+        assert!(!check("byr", "192"));
+        assert!(!check("byr", "abc"));
+    }
+
+    #[test]
+    fn test_iyr() {
+        assert!(check("iyr", "2010"));
+        assert!(check("iyr", "2020"));
+        assert!(!check("iyr", "2009"));
+        assert!(!check("iyr", "2021"));
+    }
+
+    #[test]
+    fn test_eyr() {
+        assert!(check("eyr", "2020"));
+        assert!(check("eyr", "2030"));
+        assert!(!check("eyr", "2019"));
+        assert!(!check("eyr", "2031"));
+    }
+
+    #[test]
+    fn test_hgt() {
+        assert!(check("hgt", "60in"));
+        assert!(check("hgt", "190cm"));
+        assert!(!check("hgt", "190in"));
+        assert!(!check("hgt", "190"));
+    }
+
+    #[test]
+    fn test_hcl() {
+        assert!(check("hcl", "#123abc"));
+        assert!(!check("hcl", "#123abz"));
+        assert!(!check("hcl", "123abc"));
+    }
+
+    #[test]
+    fn test_ecl() {
+        assert!(check("ecl", "amb"));
+        assert!(check("ecl", "blu"));
+        assert!(check("ecl", "brn"));
+        assert!(check("ecl", "gry"));
+        assert!(check("ecl", "grn"));
+        assert!(check("ecl", "hzl"));
+        assert!(check("ecl", "oth"));
+
+        assert!(!check("ecl", ""));
+        assert!(!check("ecl", "wat"));
+    }
+
+    #[test]
+    fn test_pid() {
+        assert!(check("pid", "000000001"));
+        assert!(check("pid", "100000002"));
+
+        assert!(!check("pid", "10000002"));
+        assert!(!check("pid", "1a0000002"));
+        assert!(!check("pid", "100 00002"));
+        assert!(!check("pid", "0123456789"));
+        assert!(!check("pid", "1234567890"));
+    }
+}
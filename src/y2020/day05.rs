@@ -0,0 +1,133 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+
+pub struct DayFive {
+    seat_ids: Vec<u32>,
+}
+
+impl DayFive {
+    pub fn seat_ids(&self) -> &[u32] {
+        &self.seat_ids
+    }
+}
+
+impl Solution for DayFive {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayFive {
+            seat_ids: Text::new(input).parse_lines(|s| Ok(Seat::new(s)?.seat_id()))?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u32> {
+        self.seat_ids
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| Error::no_solution("no seats"))
+    }
+
+    // The one seat missing between the first and last taken
+    fn part_two(&self) -> Result<u32> {
+        let mut ids = self.seat_ids.clone();
+        ids.sort_unstable();
+        let gaps: Vec<(u32, u32)> = ids
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .filter(|&(before, after)| after > before + 1)
+            .collect();
+        match gaps[..] {
+            [(before, after)] if after == before + 2 => Ok(before + 1),
+            _ => Err(Error::no_solution(format!(
+                "expected one missing seat, found {} gaps",
+                gaps.len()
+            ))),
+        }
+    }
+}
+
+/// A boarding pass, e.g. "FBFBBFFRLR"
+pub struct Seat<'a> {
+    row: &'a str,
+    column: &'a str,
+}
+
+impl<'a> Seat<'a> {
+    pub fn new(data: impl Into<Text<'a>>) -> Result<Seat<'a>> {
+        let data = data.into();
+        // 7 of F/B for the row, then 3 of L/R for the column
+        if data.as_str().len() != 10 {
+            return Err(data.error(format!("Expected 10 characters, found '{}'", data.as_str())));
+        }
+        let expected = |i| if i < 7 { "FB" } else { "LR" };
+        if let Some((_, (at, c))) = data
+            .chars()
+            .enumerate()
+            .find(|&(i, (_, c))| !expected(i).contains(c))
+        {
+            return Err(at.error(format!("Unexpected char: {:?}", c)));
+        }
+
+        let (row, column) = data.as_str().split_at(7);
+        Ok(Seat { row, column })
+    }
+
+    pub fn row(&self) -> u32 {
+        self.row.chars().enumerate().fold(0, |acc, (i, c)| {
+            acc + if c == 'B' { 2u32.pow(6 - i as u32) } else { 0 }
+        })
+    }
+
+    pub fn column(&self) -> u32 {
+        self.column.chars().enumerate().fold(0, |acc, (i, c)| {
+            acc + if c == 'R' { 2u32.pow(2 - i as u32) } else { 0 }
+        })
+    }
+
+    pub fn seat_id(&self) -> u32 {
+        8 * self.row() + self.column()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert!(Seat::new("FFFFFFFLLL").unwrap().seat_id() == 0);
+        assert!(Seat::new("BFFFBBFRRR").unwrap().seat_id() == 567);
+        assert!(Seat::new("FFFBBBFRRR").unwrap().seat_id() == 119);
+        assert!(Seat::new("BBFFBBFRLL").unwrap().seat_id() == 820);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Seat::new("FFFFFFFLL").is_err());
+        let error = DayFive::parse("BFFFBBFRRR\nBFFFBBFRBR").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 9: Unexpected char: 'B'");
+
+        // Seats (by ID) with no gap, a gap of two, and repeats
+        let seats = |ids: &[u32]| DayFive {
+            seat_ids: ids.to_vec(),
+        };
+        assert!(seats(&[7, 5, 4]).part_two().unwrap() == 6);
+        assert!(seats(&[4, 4, 4, 6]).part_two().unwrap() == 5);
+        assert!(matches!(
+            seats(&[4, 5, 6]).part_two(),
+            Err(Error::NoSolution(_))
+        ));
+        assert!(matches!(
+            seats(&[4, 7]).part_two(),
+            Err(Error::NoSolution(_))
+        ));
+        assert!(matches!(
+            seats(&[4, 6, 8]).part_two(),
+            Err(Error::NoSolution(_))
+        ));
+        assert!(matches!(seats(&[]).part_two(), Err(Error::NoSolution(_))));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::Result;
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub struct DaySix {
+    groups: Vec<Group>,
+}
+
+impl DaySix {
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+}
+
+impl Solution for DaySix {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DaySix {
+            groups: Text::new(input)
+                .blocks()
+                .into_iter()
+                .map(Group::new)
+                .collect(),
+        })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        Ok(self.groups.iter().map(|group| group.anyones()).sum())
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(self.groups.iter().map(|group| group.everyones()).sum())
+    }
+}
+
+/// Each person's answers, one string per person
+pub struct Group {
+    pub people: Vec<String>,
+}
+
+impl Group {
+    pub fn new<'a>(input: impl Into<Text<'a>>) -> Group {
+        Group {
+            people: input
+                .into()
+                .lines()
+                .iter()
+                .map(|person| person.as_str().to_string())
+                .collect(),
+        }
+    }
+
+    // Questions anyone answered yes to
+    pub fn anyones(&self) -> usize {
+        self.people
+            .iter()
+            .fold(HashSet::new(), |set, person| {
+                let mine: HashSet<_> = person.chars().collect();
+                set.union(&mine).copied().collect()
+            })
+            .len()
+    }
+
+    // Questions everyone answered yes to
+    pub fn everyones(&self) -> usize {
+        self.people[0]
+            .chars()
+            .filter(|&c| self.people.iter().all(|person| person.contains(c)))
+            .count()
+    }
+}
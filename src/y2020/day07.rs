@@ -0,0 +1,187 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+lazy_static! {
+    static ref BAAAAGS: Regex = Regex::new(r"^(\d+) (\w+ \w+) bags?\.?$").unwrap();
+}
+
+pub struct DaySeven {
+    bags: BagCollection,
+}
+
+impl DaySeven {
+    pub fn bags(&self) -> &BagCollection {
+        &self.bags
+    }
+}
+
+impl Solution for DaySeven {
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DaySeven {
+            bags: BagCollection::parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        solve_7a(&self.bags)
+    }
+
+    fn part_two(&self) -> Result<u32> {
+        solve_7b(&self.bags)
+    }
+}
+
+fn solve_7a(bags: &BagCollection) -> Result<usize> {
+    let mut shiny_parents: HashMap<String, bool> = match bags.bags_to_parents.get("shiny gold") {
+        None => return Err(Error::no_solution("no bag can hold a shiny gold bag")),
+        Some(shiny) => shiny
+            .iter()
+            .map(|parent| (parent.to_owned(), false))
+            .collect(),
+    };
+
+    while let Some(unvisited_parent) = shiny_parents
+        .iter()
+        .filter(|(_, visited)| !**visited)
+        .map(|(parent, _)| parent.to_owned())
+        .next()
+    {
+        *shiny_parents.get_mut(&unvisited_parent).unwrap() = true;
+        if let Some(new_parents) = bags.bags_to_parents.get(&unvisited_parent) {
+            for new_parent in new_parents {
+                if !shiny_parents.contains_key(new_parent) {
+                    shiny_parents.insert(new_parent.to_owned(), false);
+                }
+            }
+        }
+    }
+
+    Ok(shiny_parents.len())
+}
+
+fn solve_7b(bags: &BagCollection) -> Result<u32> {
+    let mut contains: HashMap<&str, u32> = HashMap::new();
+
+    count_them(&mut contains, bags, "shiny gold")
+}
+
+fn count_them<'a>(
+    contains: &mut HashMap<&'a str, u32>,
+    bags: &'a BagCollection,
+    name: &'a str,
+) -> Result<u32> {
+    if let Some(count) = contains.get(name) {
+        return Ok(*count);
+    }
+
+    let children = bags
+        .bags_to_children
+        .get(name)
+        .ok_or_else(|| Error::unsupported(format!("no rule for {} bags", name)))?;
+    let mut count = 0;
+    for (child, n) in children {
+        count += n * (1 + count_them(contains, bags, child)?);
+    }
+    contains.insert(name, count);
+    Ok(count)
+}
+
+/// The bag rules, looked up in either direction
+pub struct BagCollection {
+    bags_to_children: HashMap<String, Vec<(String, u32)>>,
+    bags_to_parents: HashMap<String, HashSet<String>>,
+}
+
+impl BagCollection {
+    pub fn parse(input: &str) -> Result<BagCollection> {
+        let mut btc: HashMap<String, Vec<(String, u32)>> = HashMap::new();
+        let mut btp: HashMap<String, HashSet<String>> = HashMap::new();
+        for (parent, child_list) in Text::new(input).parse_lines(BagCollection::parse_row)? {
+            for (child, _) in &child_list {
+                btp.entry(child.clone()).or_default().insert(parent.clone());
+            }
+
+            btc.insert(parent, child_list);
+        }
+
+        Ok(BagCollection {
+            bags_to_children: btc,
+            bags_to_parents: btp,
+        })
+    }
+
+    // The bags directly inside this one, and how many of each
+    pub fn children(&self, name: &str) -> Option<&[(String, u32)]> {
+        self.bags_to_children.get(name).map(Vec::as_slice)
+    }
+
+    // The bags which can directly hold this one
+    pub fn parents(&self, name: &str) -> Option<&HashSet<String>> {
+        self.bags_to_parents.get(name)
+    }
+
+    fn parse_row(row: Text) -> Result<(String, Vec<(String, u32)>)> {
+        // "dark red bags contain 4 bright chartreuse bags.";
+        // "drab beige bags contain 5 bright teal bags, 1 faded cyan bag, 2 muted yellow bags, 1 dim lime bag.";
+        // "dotted violet bags contain no other bags.";
+        let (parent, children) = row.split_once(" bags contain ").ok_or_else(|| {
+            row.error(format!(
+                "Expected '<colour> bags contain ...', found '{}'",
+                row.as_str()
+            ))
+        })?;
+        let parent = parent.as_str().to_string();
+        let children = match children.as_str() {
+            "no other bags." => Vec::new(),
+            _ => {
+                // r"(\d+) (\w \w) bags?[,.]"
+                children
+                    .split(", ")
+                    .iter()
+                    .map(|bags| {
+                        let captures = bags.captures(&BAAAAGS, "'<n> <colour> bags'")?;
+                        Ok((
+                            captures.group(2)?.as_str().to_string(),
+                            captures.group(1)?.num()?,
+                        ))
+                    })
+                    .collect::<Result<_>>()?
+            }
+        };
+        Ok((parent, children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DaySeven::parse(
+            "light red bags contain 1 bright white bag.\nbright white bags hold nothing.",
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+        let error = DaySeven::parse("light red bags contain 1 bright white bag, 2 muted bags.")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 1, column 44: Expected '<n> <colour> bags', found '2 muted bags.'"
+        );
+
+        let day = DaySeven::parse("shiny gold bags contain 2 dark red bags.").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::NoSolution(_))));
+        assert!(matches!(day.part_two(), Err(Error::Unsupported(_))));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub struct DayEight {
+    instructions: Vec<Opcode>,
+}
+
+impl DayEight {
+    pub fn instructions(&self) -> &[Opcode] {
+        &self.instructions
+    }
+}
+
+impl Solution for DayEight {
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayEight {
+            instructions: Text::new(input).parse_lines(Opcode::from)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<isize> {
+        match run_program(&self.instructions) {
+            Ok(acc) => Err(Error::no_solution(format!(
+                "program terminated with value {} instead of looping",
+                acc
+            ))),
+            Err(acc) => Ok(acc),
+        }
+    }
+
+    fn part_two(&self) -> Result<isize> {
+        solve_8b(&self.instructions)
+    }
+}
+
+fn solve_8b(initial_instructions: &[Opcode]) -> Result<isize> {
+    let nop_or_jmp: Vec<usize> = initial_instructions
+        .iter()
+        .enumerate()
+        .filter(|(_i, v)| v.is_nop_or_jmp())
+        .map(|(i, _)| i)
+        .collect();
+    for index in nop_or_jmp.iter() {
+        if let Ok(answer) = run_program(&swap_nop_jmp(initial_instructions, *index)) {
+            return Ok(answer);
+        }
+    }
+    Err(Error::no_solution("no single nop/jmp swap terminates"))
+}
+
+fn swap_nop_jmp(instructions: &[Opcode], index: usize) -> Vec<Opcode> {
+    let mut new_instructions = instructions.to_owned();
+    match new_instructions[index] {
+        Opcode::Nop(val) => {
+            new_instructions[index] = Opcode::Jmp(val);
+        }
+        Opcode::Jmp(val) => {
+            new_instructions[index] = Opcode::Nop(val);
+        }
+        Opcode::Acc(_) => panic!("Can't toggle Acc instruction (at index {:})", index),
+    }
+    new_instructions
+}
+
+// Ok = program terminated; here is the acc value.
+// Err = program looped, here is the acc value just before entering the infinite loop.
+pub fn run_program(instructions: &[Opcode]) -> std::result::Result<isize, isize> {
+    let mut visited: HashSet<isize> = HashSet::new();
+
+    let mut ip = 0isize;
+    let mut acc = 0isize;
+
+    loop {
+        if !visited.insert(ip) {
+            return Err(acc);
+        };
+
+        if let Some(op) = &instructions.get(ip as usize) {
+            match op {
+                Opcode::Nop(_) => {
+                    ip += 1;
+                }
+                Opcode::Acc(val) => {
+                    acc += val;
+                    ip += 1;
+                }
+                Opcode::Jmp(val) => {
+                    ip += val;
+                }
+            }
+        } else {
+            return Ok(acc);
+        }
+    }
+}
+
+/// One instruction of the handheld's boot code
+#[derive(Clone, Debug, PartialEq)]
+pub enum Opcode {
+    Nop(isize),
+    Acc(isize),
+    Jmp(isize),
+}
+
+impl Opcode {
+    pub fn from<'a>(s: impl Into<Text<'a>>) -> Result<Opcode> {
+        let s = s.into();
+        let (opcode, value) = s
+            .split_once(" ")
+            .ok_or_else(|| s.error(format!("Expected '<op> <value>', found '{}'", s.as_str())))?;
+        let value = value.num()?;
+        match opcode.as_str() {
+            "nop" => Ok(Opcode::Nop(value)),
+            "acc" => Ok(Opcode::Acc(value)),
+            "jmp" => Ok(Opcode::Jmp(value)),
+            _ => Err(opcode.error(format!("Unknown opcode '{}'", opcode.as_str()))),
+        }
+    }
+
+    fn is_nop_or_jmp(&self) -> bool {
+        matches!(*self, Opcode::Nop(_) | Opcode::Jmp(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayEight::parse("nop +0\nmul +1").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 1: Unknown opcode 'mul'");
+        let error = DayEight::parse("acc +x").err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: Some(1),
+                column: Some(5),
+                ..
+            }
+        ));
+
+        let day = DayEight::parse("nop +0\nacc +1").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::NoSolution(_))));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::parse_num;
+use crate::solution::Solution;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+pub struct DayNine {
+    input: Vec<u64>,
+    preamble_length: usize,
+}
+
+impl Solution for DayNine {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayNine {
+            input: Text::new(input).parse_lines(|line| line.num())?,
+            preamble_length: 25,
+        })
+    }
+
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "preamble" => match parse_num(value)? {
+                0 => Err(Error::usage("preamble must be at least 1")),
+                preamble_length => Ok(DayNine {
+                    preamble_length,
+                    ..self
+                }),
+            },
+            _ => Err(Error::usage(format!("No parameter '{}' for day 9", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        Ok(self.input[find_invalid_number(&self.input, self.preamble_length)?])
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        solve_9b(&self.input, self.preamble_length)
+    }
+}
+
+fn solve_9b(input: &[u64], preamble_length: usize) -> Result<u64> {
+    let target = input[find_invalid_number(input, preamble_length)?];
+    if input.len() < 2 {
+        return Err(Error::no_solution("need at least 2 numbers for a set"));
+    }
+
+    // Now to find the contiguous set.  Values are all positive, so use a concertina.
+    let mut low = 0usize;
+    let mut high = 1usize;
+    let mut sum = input[low] + input[high];
+    loop {
+        match sum.cmp(&target) {
+            // The set has to be at least two numbers
+            Ordering::Equal if high > low => {
+                verbose!("{:} .. {:} = {:}", low, high, sum);
+                let slice = &input[low..high + 1];
+                return Ok(*slice.iter().min().unwrap() + *slice.iter().max().unwrap());
+            }
+            Ordering::Less | Ordering::Equal => {
+                // Too small - extend the top of the concertina
+                high += 1;
+                sum += input.get(high).ok_or_else(|| {
+                    Error::no_solution(format!("no contiguous set sums to {}", target))
+                })?;
+            }
+            Ordering::Greater => {
+                // Too big - retract the bottom of the concertina
+                sum -= input[low];
+                low += 1;
+            }
+        };
+    }
+}
+
+fn find_invalid_number(input: &[u64], preamble_length: usize) -> Result<usize> {
+    if input.len() <= preamble_length {
+        return Err(Error::unsupported(format!(
+            "need more than {} numbers, found {}",
+            preamble_length,
+            input.len()
+        )));
+    }
+
+    let mut possible_sums: VecDeque<Vec<u64>> = VecDeque::new();
+    for i in 0..preamble_length {
+        let mut sums: Vec<u64> = Vec::new();
+
+        let lower: isize = i as isize - preamble_length as isize;
+        let upper: isize = i as isize;
+        for j in lower..upper {
+            let sum: u64 = match j {
+                index if index < 0 => 0,
+                index => input[i] + input[index as usize],
+            };
+            sums.push(sum);
+        }
+        possible_sums.push_back(sums);
+    }
+
+    for i in preamble_length..input.len() {
+        if !possible_sums
+            .iter()
+            .enumerate()
+            .any(|(j, sums)| sums[sums.len() - j - 1..].contains(&input[i]))
+        {
+            // println!("{:?} - {:?}", i, input[i]);
+            // println!("{:?}", possible_sums);
+            return Ok(i);
+        }
+
+        possible_sums.pop_front();
+        let mut sums: Vec<u64> = Vec::new();
+        for j in i - preamble_length..i {
+            sums.push(input[i] + input[j]);
+        }
+        assert!(sums.len() == preamble_length);
+        possible_sums.push_back(sums);
+    }
+    Err(Error::no_solution("all numbers are possible"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_9a_again() {
+        let mut input: Vec<u64> = (1u64..26).collect();
+        input.push(26);
+        input.push(49);
+        input.push(100);
+        assert!(input[find_invalid_number(&input, 25).unwrap()] == 100);
+
+        assert!(matches!(
+            find_invalid_number(&input[..26], 25),
+            Err(Error::NoSolution(_))
+        ));
+        assert!(matches!(
+            find_invalid_number(&input[..20], 25),
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let day = DayNine::parse("5").unwrap();
+        assert!(matches!(
+            day.with_param("preamble", "0"),
+            Err(Error::Usage(_))
+        ));
+        assert!(matches!(solve_9b(&[5], 0), Err(Error::NoSolution(_))));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+
+pub struct DayTen {
+    one_runs: Vec<u32>,
+    threes: u32,
+}
+
+impl Solution for DayTen {
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let (one_runs, threes) = get_gaps(input)?;
+        Ok(DayTen { one_runs, threes })
+    }
+
+    fn part_one(&self) -> Result<u32> {
+        Ok(self.one_runs.iter().sum::<u32>() * self.threes)
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        solve_10b(&self.one_runs)
+    }
+}
+
+fn solve_10b(one_runs: &[u32]) -> Result<u64> {
+    // Can split at any gap of 3 - there's only one way
+    // to cross the gap.  So each string of gaps-of-one
+    // has a variety of ways through - the only requirement
+    // is you hit the final number in the group (before the
+    // gap of three).
+    // Calculated these by hand, after seeing max number of
+    // consecutive ones in my input was small.
+    one_runs.iter().try_fold(1, |acc, x| {
+        Ok(acc
+            * match x {
+                0 => 1, // consecutive 3s
+                1 => 1,
+                2 => 2,
+                3 => 4,
+                4 => 7,
+                too_big => {
+                    return Err(Error::unsupported(format!(
+                        "run of {} gaps of one - calculate combinations for this",
+                        too_big
+                    )))
+                }
+            })
+    })
+}
+
+fn get_gaps(input: &str) -> Result<(Vec<u32>, u32)> {
+    let mut adaptors: Vec<u32> = Text::new(input).parse_lines(|line| line.num())?;
+    adaptors.sort_unstable();
+    // The device is 3 above the biggest adaptor
+    let device = adaptors
+        .last()
+        .ok_or_else(|| Error::parse("No adaptors"))?
+        .checked_add(3)
+        .ok_or_else(|| Error::parse("Adaptor too big"))?;
+    adaptors.push(device);
+
+    let mut one_runs: Vec<u32> = vec![0];
+    let mut threes = 0u32;
+
+    for i in 0..adaptors.len() {
+        let other = match i {
+            0 => 0, // Outlet
+            _ => adaptors[i - 1],
+        };
+        match adaptors[i] - other {
+            1 => *one_runs.last_mut().unwrap() += 1,
+            3 => {
+                threes += 1;
+                one_runs.push(0);
+            }
+            // Input is friendly - there are only gaps of 1 or 3
+            oops => {
+                return Err(Error::unsupported(format!(
+                    "gap of {} up to adaptor {}",
+                    oops, adaptors[i]
+                )))
+            }
+        };
+    }
+
+    verbose!("{:?} {:}", one_runs, threes);
+    Ok((one_runs, threes))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_unsupported() {
+        assert!(matches!(
+            DayTen::parse("1\n3\n4"),
+            Err(Error::Unsupported(_))
+        ));
+        let day = DayTen::parse("1\n2\n3\n4\n5").unwrap();
+        assert!(matches!(day.part_two(), Err(Error::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(DayTen::parse(""), Err(Error::Parse { .. })));
+        assert!(matches!(DayTen::parse("\n\n"), Err(Error::Parse { .. })));
+        assert!(matches!(
+            DayTen::parse("4294967295"),
+            Err(Error::Parse { .. })
+        ));
+    }
+    // (0) 1 4 5 6 7 10 11 12 15 16 19 (22)
+    //    1 3 111 3 11 3 1 33
+}
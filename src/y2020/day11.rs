@@ -0,0 +1,103 @@
+use crate::error::Result;
+use crate::grid::{Grid, Point, ADJACENT};
+use crate::parse::Text;
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+pub struct DayEleven {
+    seats: Grid<Seat>,
+}
+
+impl Solution for DayEleven {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let seats = Text::new(input).grid(|c| match c {
+            '.' => Some(Seat::Floor),
+            'L' => Some(Seat::Empty),
+            '#' => Some(Seat::Occupied),
+            _ => None,
+        })?;
+
+        Ok(DayEleven { seats })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        Ok(solve(self.seats.clone(), adjacent, 4))
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(solve(self.seats.clone(), visible, 5))
+    }
+}
+
+// Move people around until nobody wants to move, then count the full seats.
+// `occupied` counts the full seats someone cares about, and they leave if
+// there are `limit` or more.
+fn solve(mut seats: Grid<Seat>, occupied: fn(&Grid<Seat>, Point) -> usize, limit: usize) -> usize {
+    loop {
+        let next = seats.map(|point, &seat| match seat {
+            Seat::Occupied if occupied(&seats, point) >= limit => Seat::Empty,
+            Seat::Empty if occupied(&seats, point) == 0 => Seat::Occupied,
+            _ => seat,
+        });
+        if next == seats {
+            break;
+        }
+        seats = next;
+    }
+
+    verbose!("{}\n", seats.render(|_, &seat| render(seat)));
+    seats
+        .iter()
+        .filter(|(_, &seat)| seat == Seat::Occupied)
+        .count()
+}
+
+fn adjacent(seats: &Grid<Seat>, point: Point) -> usize {
+    seats
+        .neighbours(point, &ADJACENT)
+        .filter(|(_, &seat)| seat == Seat::Occupied)
+        .count()
+}
+
+// The number of directions in which you can see an occupied seat, stopping at
+// the first seat (occupied or empty)
+fn visible(seats: &Grid<Seat>, point: Point) -> usize {
+    ADJACENT
+        .iter()
+        .filter(|&&step| {
+            seats
+                .ray(point, step)
+                .map(|(_, &seat)| seat)
+                .find(|&seat| seat != Seat::Floor)
+                == Some(Seat::Occupied)
+        })
+        .count()
+}
+
+fn render(seat: Seat) -> char {
+    match seat {
+        Seat::Floor => '.',
+        Seat::Empty => 'L',
+        Seat::Occupied => '#',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayEleven::parse("L.L\nL#x").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 3: Unexpected char: 'x'");
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::Result;
+use crate::parse::Text;
+use crate::solution::Solution;
+
+pub struct DayTwelve {
+    instructions: Vec<Instruction>,
+}
+
+impl DayTwelve {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+impl Solution for DayTwelve {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayTwelve {
+            instructions: Text::new(input).parse_lines(Instruction::parse)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u32> {
+        Ok(part_one(&self.instructions))
+    }
+
+    fn part_two(&self) -> Result<u32> {
+        Ok(part_two(&self.instructions))
+    }
+}
+
+/// A navigation instruction, e.g. "F10", "R90"
+pub struct Instruction {
+    pub code: char,
+    pub value: i32,
+}
+
+impl Instruction {
+    pub fn parse<'a>(s: impl Into<Text<'a>>) -> Result<Instruction> {
+        let s = s.into();
+        let (at, code) = s
+            .chars()
+            .next()
+            .ok_or_else(|| s.error("Empty instruction"))?;
+        if !"NESWLRF".contains(code) {
+            return Err(at.error(format!("Unexpected code {:?}", code)));
+        }
+        let (_, rest) = s.split_at(code.len_utf8());
+        let value: i32 = rest.num()?;
+
+        // Only right angles
+        if (code == 'L' || code == 'R') && value % 90 != 0 {
+            return Err(rest.error(format!("Can't turn {} degrees", value)));
+        }
+
+        Ok(Instruction { code, value })
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> u32 {
+    let mut x = 0i32;
+    let mut y = 0i32;
+    let mut facing = 90i32;
+
+    for &Instruction { code, value } in instructions.iter() {
+        match code {
+            'N' => y += value,
+            'E' => x += value,
+            'S' => y -= value,
+            'W' => x -= value,
+            'L' => facing = (facing - value).rem_euclid(360),
+            'R' => facing = (facing + value).rem_euclid(360),
+            'F' => match facing {
+                0 => y += value,
+                90 => x += value,
+                180 => y -= value,
+                270 => x -= value,
+                other => unreachable!("Unexpected direction {:}", other),
+            },
+            _ => unreachable!("Unexpected code/val {:}-{:}", code, value),
+        }
+    }
+    (x.abs() + y.abs()) as u32
+}
+
+fn part_two(instructions: &[Instruction]) -> u32 {
+    let mut x = 0i32;
+    let mut y = 0i32;
+    let mut waypoint = Waypoint { x: 10, y: 1 };
+
+    for &Instruction { code, value } in instructions.iter() {
+        match code {
+            'N' => waypoint.y += value,
+            'E' => waypoint.x += value,
+            'S' => waypoint.y -= value,
+            'W' => waypoint.x -= value,
+            'L' => waypoint.rotate_left(value),
+            'R' => waypoint.rotate_right(value),
+            'F' => {
+                x += value * waypoint.x;
+                y += value * waypoint.y;
+            }
+            _ => unreachable!("Unexpected code/val {:}-{:}", code, value),
+        };
+        // println!("ship {:}, {:}, waypoint {:}, {:}", x, y, waypoint.x, waypoint.y);
+    }
+    (x.abs() + y.abs()) as u32
+}
+
+struct Waypoint {
+    x: i32,
+    y: i32,
+}
+
+impl Waypoint {
+    fn rotate_left(&mut self, degrees: i32) {
+        self.rotate_right(-degrees);
+    }
+
+    fn rotate_right(&mut self, degrees: i32) {
+        match degrees.rem_euclid(360) {
+            0 => {}
+            90 => {
+                let old_x = self.x;
+                self.x = self.y;
+                self.y = -old_x;
+            }
+            180 => {
+                self.x = -self.x;
+                self.y = -self.y;
+            }
+            270 => {
+                let old_x = self.x;
+                self.x = -self.y;
+                self.y = old_x;
+            }
+            other => unreachable!("Unexpected direction {:}", other),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayTwelve::parse("F10\nR45").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 2: Can't turn 45 degrees");
+        assert!(DayTwelve::parse("F10\nX3").is_err());
+        assert!(DayTwelve::parse("Fx").is_err());
+    }
+}
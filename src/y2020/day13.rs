@@ -0,0 +1,121 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+
+pub struct DayThirteen {
+    now: u32,
+    buses: String,
+}
+
+impl Solution for DayThirteen {
+    type PartOne = u64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let instructions = Text::new(input).non_empty_lines();
+        if instructions.len() != 2 {
+            return Err(Error::parse(format!(
+                "Expected 2 lines, found {}",
+                instructions.len()
+            )));
+        }
+
+        let now = instructions[0].num()?;
+        let buses = instructions[1];
+        for bus in buses.split(",") {
+            if bus.as_str() != "x" && bus.num::<u32>()? == 0 {
+                return Err(bus.error("Bus IDs can't be 0"));
+            }
+        }
+
+        Ok(DayThirteen {
+            now,
+            buses: buses.as_str().to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        if self.buses.split(',').all(|bus| bus == "x") {
+            return Err(Error::no_solution("no buses in service"));
+        }
+        Ok(part_one(self.now, &self.buses))
+    }
+
+    fn part_two(&self) -> Result<i64> {
+        Ok(timestamp(&self.buses))
+    }
+}
+
+// In a u64, as a wait times a bus ID can be too big for a u32
+fn part_one(now: u32, buses: &str) -> u64 {
+    buses
+        .split(',')
+        .fold((u64::MAX, 0), |(min, answer), bus| {
+            if let Ok(bus_time) = bus.parse::<u64>() {
+                let time_to_wait = bus_time - u64::from(now) % bus_time;
+                if time_to_wait < min {
+                    (time_to_wait, time_to_wait * bus_time)
+                } else {
+                    (min, answer)
+                }
+            } else {
+                (min, answer)
+            }
+        })
+        .1
+}
+
+fn timestamp(input: &str) -> i64 {
+    // input like "3,x,5,7"
+    let mut multiplyer = 1i64;
+    let mut diff = 0i64;
+    for (i, s) in input.split(',').enumerate() {
+        if let Ok(val) = s.parse::<i64>() {
+            match i {
+                0 => multiplyer *= val,
+                i => {
+                    for n in 0..val {
+                        if (n * multiplyer - diff) % val == (val - (i as i64 % val)) {
+                            diff = multiplyer * val - (n * multiplyer - diff);
+                            multiplyer *= val;
+                            // println!("i {:}, val {:}, n {:}, mult {:}, diff {:}", i, val, n, multiplyer, diff);
+                            break; // next bus
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    multiplyer - diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayThirteen::parse("939\n7,13,x,0").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2, column 8: Bus IDs can't be 0");
+        assert!(DayThirteen::parse("939").is_err());
+
+        let day = DayThirteen::parse("939\nx,x").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::NoSolution(_))));
+
+        // Bigger than a u32
+        let day = DayThirteen::parse("4294967290\n4294967291").unwrap();
+        assert!(day.part_one().unwrap() == 4294967291);
+        let day = DayThirteen::parse("1\n4294967295").unwrap();
+        assert!(day.part_one().unwrap() == 4294967294 * 4294967295);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert!(timestamp("17,x,13,19") == 3417);
+        assert!(timestamp("67,7,59,61") == 754018);
+        assert!(timestamp("67,x,7,59,61") == 779210);
+        assert!(timestamp("67,7,x,59,61") == 1261476);
+        assert!(timestamp("1789,37,47,1889") == 1202161486);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::error::Result;
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+const WIDTH: usize = 36;
+
+pub struct DayFourteen {
+    program: Vec<Line>,
+}
+
+impl DayFourteen {
+    pub fn program(&self) -> &[Line] {
+        &self.program
+    }
+}
+
+impl Solution for DayFourteen {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let input = Text::new(input);
+        let program = input.parse_lines(Line::parse)?;
+        if !matches!(program.first(), Some(Line::Mask(_))) {
+            // The first line that's there, blank lines aside
+            let first = input.non_empty_lines().first().copied().unwrap_or(input);
+            return Err(first.error("Program must start with a mask"));
+        }
+
+        Ok(DayFourteen { program })
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        Ok(part_one(&self.program))
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        Ok(part_two(&self.program))
+    }
+}
+
+/// One line of the initialization program
+pub enum Line {
+    // e.g. "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"
+    Mask(String),
+    // e.g. "mem[8] = 11"
+    Mem(u64, u64),
+}
+
+impl Line {
+    pub fn parse<'a>(line: impl Into<Text<'a>>) -> Result<Line> {
+        let line = line.into();
+        let (first, second) = line.split_once(" = ").ok_or_else(|| {
+            line.error(format!(
+                "Expected '<lhs> = <rhs>', found '{}'",
+                line.as_str()
+            ))
+        })?;
+
+        if first.as_str() == "mask" {
+            if second.as_str().len() != WIDTH {
+                return Err(second.error(format!(
+                    "Expected a {}-bit mask, found {} bits",
+                    WIDTH,
+                    second.as_str().len()
+                )));
+            }
+            if let Some((at, _)) = second.chars().find(|&(_, c)| !"01X".contains(c)) {
+                return Err(at.error("Mask must be 0, 1 or X"));
+            }
+            return Ok(Line::Mask(second.as_str().to_string()));
+        }
+
+        let digits = first
+            .strip_prefix("mem[")
+            .and_then(|mem| mem.strip_suffix("]"))
+            .ok_or_else(|| {
+                first.error(format!(
+                    "Expected 'mask' or 'mem[..]', found '{}'",
+                    first.as_str()
+                ))
+            })?;
+        let address: u64 = digits.num()?;
+        if address >> WIDTH != 0 {
+            return Err(first.error(format!("Address {} is wider than {} bits", address, WIDTH)));
+        }
+        let value = second.num()?;
+
+        Ok(Line::Mem(address, value))
+    }
+}
+
+fn part_one(program: &[Line]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+
+    let mut or_mask: u64 = 0;
+    let mut and_mask: u64 = 0;
+
+    for line in program.iter() {
+        match line {
+            Line::Mask(mask) => {
+                or_mask = u64::from_str_radix(&mask.replace('X', "0"), 2).unwrap();
+                and_mask = u64::from_str_radix(&mask.replace('X', "1"), 2).unwrap();
+            }
+            Line::Mem(address, value) => {
+                memory.insert(*address, (value | or_mask) & and_mask);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn part_two(program: &[Line]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+
+    let mut mask: &str = "";
+
+    for line in program.iter() {
+        match line {
+            Line::Mask(new_mask) => {
+                mask = new_mask;
+            }
+            Line::Mem(address, value) => {
+                for addr in addresses_for_mask(mask, *address).iter() {
+                    memory.insert(*addr, *value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn addresses_for_mask(mask: &str, address: u64) -> Vec<u64> {
+    // 0010_10XX0110_X0X01000_1001X010_11XX010X
+    static CHAR_0: u8 = 0x30;
+    static CHAR_1: u8 = 0x31;
+    static CHAR_X: u8 = 0x58;
+
+    assert!(mask.len() == WIDTH);
+    let address_bin = format!("{:#038b}", address).as_bytes()[2..].to_owned(); // ignore the '0b' prefix -- which takes up 2 of the #38 chars!!!
+    let mask_bin = mask.as_bytes();
+    let mut base_address = String::new();
+
+    for i in 0..mask_bin.len() {
+        base_address.push(match mask_bin[i] {
+            x if x == CHAR_X => 'X',
+            x if x == CHAR_1 => '1',
+            x if x == CHAR_0 => match address_bin[i] {
+                x if x == CHAR_1 => '1',
+                x if x == CHAR_0 => '0',
+                bad => panic!("Unexpected char in address at index {:}: {:?}", i, bad),
+            },
+            bad => panic!("Unexpected char in mask at index {:}: {:?}", i, bad),
+        });
+    }
+
+    let mut finished_addresses: Vec<u64> = Vec::new();
+    let mut potential_addresses: Vec<String> = Vec::new();
+    potential_addresses.push(base_address);
+
+    while let Some(x_address) = potential_addresses.pop() {
+        if let Some(index) = x_address.find('X') {
+            potential_addresses.push(replace_x_at(&x_address, index, "0"));
+            potential_addresses.push(replace_x_at(&x_address, index, "1"));
+        } else {
+            finished_addresses.push(u64::from_str_radix(&x_address, 2).unwrap());
+        }
+    }
+
+    finished_addresses
+}
+
+fn replace_x_at(binary_string: &str, index: usize, value: &str) -> String {
+    let mut new_string = binary_string[..index].to_owned();
+    new_string.push_str(value);
+    new_string.push_str(&binary_string[index + 1..]);
+    new_string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let mask = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X";
+        let error = DayFourteen::parse(&format!("{}\nmem[8] = x", mask))
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 2, column 10: Expected a number, found 'x'"
+        );
+        let error = DayFourteen::parse(&mask.replace('1', "2")).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 1, column 37: Mask must be 0, 1 or X");
+        let error = DayFourteen::parse("\nmem[8] = 11").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 2: Program must start with a mask");
+    }
+
+    #[test]
+    fn replace() {
+        assert!(replace_x_at("0X001XX", 1, "0") == "00001XX");
+        assert!(replace_x_at("X1101X", 0, "0") == "01101X");
+        assert!(replace_x_at("X1101X", 0, "1") == "11101X");
+        assert!(replace_x_at("X1101X", 5, "0") == "X11010");
+        assert!(replace_x_at("X1101X", 5, "1") == "X11011");
+    }
+
+    #[test]
+    fn addresses_from() {
+        let addresses = addresses_for_mask("000000000000000000000000000000X1001X", 42);
+        println!("{:?}", addresses);
+        println!(
+            "{:?}",
+            addresses
+                .iter()
+                .map(|&x| format!("{:#b}", x))
+                .collect::<Vec<String>>()
+        );
+        assert!(addresses.contains(&26));
+        assert!(addresses.contains(&27));
+        assert!(addresses.contains(&58));
+        assert!(addresses.contains(&59));
+    }
+}
@@ -1,7 +1,33 @@
+use crate::error::Result;
+use crate::parse::Text;
+use crate::solution::Solution;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
-pub(crate) fn part_one(starters: &[usize], target: usize) -> usize {
+pub struct DayFifteen {
+    starters: Vec<usize>,
+}
+
+impl Solution for DayFifteen {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayFifteen {
+            starters: Text::new(input).trim().nums(",")?,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        Ok(play(&self.starters, 2020))
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(play(&self.starters, 30_000_000))
+    }
+}
+
+fn play(starters: &[usize], target: usize) -> usize {
     let mut seen = Seen::new(starters);
     let mut last = starters[starters.len() - 1];
 
@@ -58,31 +84,21 @@ mod tests {
 
     #[test]
     fn test_one() {
-        assert!(part_one(&[0, 3, 6], 4) == 0);
-        assert!(part_one(&[0, 3, 6], 5) == 3);
-        assert!(part_one(&[0, 3, 6], 6) == 3);
-        assert!(part_one(&[0, 3, 6], 7) == 1);
-        assert!(part_one(&[0, 3, 6], 8) == 0);
-        assert!(part_one(&[0, 3, 6], 9) == 4);
-        assert!(part_one(&[0, 3, 6], 10) == 0);
-
-        assert!(part_one(&[1, 3, 2], 2020) == 1);
-        assert!(part_one(&[2, 1, 3], 2020) == 10);
-        assert!(part_one(&[1, 2, 3], 2020) == 27);
-        assert!(part_one(&[2, 3, 1], 2020) == 78);
-        assert!(part_one(&[3, 2, 1], 2020) == 438);
-        assert!(part_one(&[3, 1, 2], 2020) == 1836);
-    }
+        assert!(play(&[0, 3, 6], 4) == 0);
+        assert!(play(&[0, 3, 6], 5) == 3);
+        assert!(play(&[0, 3, 6], 6) == 3);
+        assert!(play(&[0, 3, 6], 7) == 1);
+        assert!(play(&[0, 3, 6], 8) == 0);
+        assert!(play(&[0, 3, 6], 9) == 4);
+        assert!(play(&[0, 3, 6], 10) == 0);
 
-    // #[test]
-    fn _time() {
-        use std::time::Instant;
-        let all_turns = [100_000, 300_000, 1_000_000, 3_000_000, 10_000_000];
-        for &turns in all_turns.iter() {
-            let start = Instant::now();
-            let _ = part_one(&[3, 1, 2], turns);
-            println!("{:}\t{:}", turns, start.elapsed().as_secs());
-        }
-        assert!(false); // Show test output in stdout
+        assert!(play(&[1, 3, 2], 2020) == 1);
+        assert!(play(&[2, 1, 3], 2020) == 10);
+        assert!(play(&[1, 2, 3], 2020) == 27);
+        assert!(play(&[2, 3, 1], 2020) == 78);
+        assert!(play(&[3, 2, 1], 2020) == 438);
+        assert!(play(&[3, 1, 2], 2020) == 1836);
+
+        assert!(DayFifteen::parse("0,,6").is_err());
     }
 }
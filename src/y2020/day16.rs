@@ -0,0 +1,286 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub struct DaySixteen {
+    constraints: HashSet<Constraint>,
+    my_ticket: Vec<u32>,
+    tickets: Vec<Vec<u32>>,
+    prefix: String,
+}
+
+impl DaySixteen {
+    pub fn constraints(&self) -> &HashSet<Constraint> {
+        &self.constraints
+    }
+
+    pub fn my_ticket(&self) -> &[u32] {
+        &self.my_ticket
+    }
+
+    // Everyone else's tickets, including invalid ones
+    pub fn tickets(&self) -> &[Vec<u32>] {
+        &self.tickets
+    }
+}
+
+impl Solution for DaySixteen {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let blocks = Text::new(input).blocks();
+
+        // First, the constraints
+        let mut constraints: HashSet<Constraint> = HashSet::new();
+        for line in blocks.iter().take(1).flat_map(Text::lines) {
+            constraints.insert(Constraint::parse(line)?);
+        }
+        if blocks.len() != 3 {
+            return Err(Error::parse(format!(
+                "Expected 3 blocks of lines, found {}",
+                blocks.len()
+            )));
+        }
+
+        // My ticket
+        let my_ticket: Vec<u32> = match under_header(blocks[1], "your ticket:")?[..] {
+            [line] => line.nums(",")?,
+            _ => return Err(blocks[1].error("Expected exactly one ticket")),
+        };
+
+        // The list of tickets
+        let mut tickets: Vec<Vec<u32>> = Vec::new();
+        for line in under_header(blocks[2], "nearby tickets:")? {
+            let ticket: Vec<u32> = line.nums(",")?;
+            if ticket.len() != my_ticket.len() {
+                return Err(line.error(format!(
+                    "Expected {} fields, found {}",
+                    my_ticket.len(),
+                    ticket.len()
+                )));
+            }
+            tickets.push(ticket);
+        }
+
+        Ok(DaySixteen {
+            constraints,
+            my_ticket,
+            tickets,
+            prefix: "departure".to_string(),
+        })
+    }
+
+    // Part two multiplies the fields whose names start with `prefix`
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "prefix" => Ok(DaySixteen {
+                prefix: value.to_string(),
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 16", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        // Ignore my own ticket for now.  Summed in a u64, as the u32 values
+        // can add up to more than a u32 holds.
+        Ok(self.tickets.iter().fold(0, |acc, ticket| {
+            acc + ticket.iter().fold(0, |acc2, &num| {
+                acc2 + match self.constraints.iter().any(|c| c.could_be(num)) {
+                    true => 0,
+                    false => u64::from(num),
+                }
+            })
+        }))
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        part_two(
+            &self.constraints,
+            &self.my_ticket,
+            &self.tickets,
+            &self.prefix,
+        )
+    }
+}
+
+fn part_two(
+    constraints: &HashSet<Constraint>,
+    my_ticket: &[u32],
+    tickets: &[Vec<u32>],
+    prefix: &str,
+) -> Result<u64> {
+    let mut tickets: Vec<&[u32]> = tickets.iter().map(|t| t.as_slice()).collect();
+    tickets.push(my_ticket);
+
+    // Now start winnowing down which constraint can apply to which field
+    let mut values_at_index: Vec<HashSet<u32>> = Vec::new();
+    for _ in tickets[0].iter() {
+        values_at_index.push(HashSet::new());
+    }
+
+    for ticket in tickets.iter() {
+        if ticket
+            .iter()
+            .any(|&val| !constraints.iter().any(|c| c.could_be(val)))
+        {
+            // invalid ticket - ignore
+            continue;
+        }
+
+        for (i, &val) in ticket.iter().enumerate() {
+            values_at_index[i].insert(val);
+        }
+    }
+
+    let mut cons_for_index: Vec<HashSet<&Constraint>> = Vec::new();
+    for vals in values_at_index.iter() {
+        let mut matching_constraints: HashSet<&Constraint> = HashSet::new();
+        for constraint in constraints.iter() {
+            if vals.iter().all(|&v| constraint.could_be(v)) {
+                matching_constraints.insert(constraint);
+            }
+        }
+        cons_for_index.push(matching_constraints);
+    }
+
+    // At this point, some constraints could still be in multiple places:
+    // [{A}, {A,B}, {A,B,C}] - we need to filter down again.
+    loop {
+        // println!("{:?}", cons_for_index.iter().map(|c| c.len()).collect::<Vec<usize>>());
+
+        if cons_for_index.iter().all(|c| c.len() == 1) {
+            break;
+        }
+
+        let uniques: HashSet<&Constraint> = cons_for_index
+            .iter()
+            .filter(|c| c.len() == 1)
+            .map(|c| *c.iter().next().unwrap())
+            .collect();
+
+        // For sets that are not solved (len=1), remove the solved ones
+        let remaining: usize = cons_for_index.iter().map(|c| c.len()).sum();
+        cons_for_index = cons_for_index
+            .into_iter()
+            .map(|cons| match cons.len() {
+                1 => cons,
+                _ => cons.difference(&uniques).copied().collect(),
+            })
+            .collect();
+
+        // Stuck, or ruled out every constraint for some field
+        if cons_for_index.iter().map(|c| c.len()).sum::<usize>() == remaining
+            || cons_for_index.iter().any(|c| c.is_empty())
+        {
+            return Err(Error::no_solution("can't narrow down which field is which"));
+        }
+    }
+
+    let my_ticket: &[u32] = tickets.pop().unwrap();
+
+    Ok(my_ticket.iter().enumerate().fold(1, |acc, (i, &val)| {
+        acc * match cons_for_index.get(i) {
+            Some(constraints) => {
+                assert!(constraints.len() == 1);
+                if constraints.iter().next().unwrap().name.starts_with(prefix) {
+                    val as u64
+                } else {
+                    1
+                }
+            }
+            _ => 1,
+        }
+    }))
+}
+
+// The lines of a block after its header, e.g. "your ticket:"
+fn under_header<'a>(block: Text<'a>, header: &str) -> Result<Vec<Text<'a>>> {
+    let lines = block.lines();
+    if lines[0].as_str() != header {
+        return Err(lines[0].error(format!(
+            "Expected '{}', found '{}'",
+            header,
+            lines[0].as_str()
+        )));
+    }
+    Ok(lines[1..].to_vec())
+}
+
+/// A ticket field rule, e.g. "class: 1-3 or 5-7"
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Constraint {
+    pub name: String,
+    pub lower_group: (u32, u32),
+    pub upper_group: (u32, u32),
+    // possible_positions: HashSet<usize>,
+}
+
+impl Constraint {
+    pub fn could_be(&self, x: u32) -> bool {
+        (self.lower_group.0 <= x && x <= self.lower_group.1)
+            || (self.upper_group.0 <= x && x <= self.upper_group.1)
+    }
+
+    pub fn parse<'a>(s: impl Into<Text<'a>>) -> Result<Constraint> {
+        // e.g. "departure location: 31-201 or 227-951"
+        let s = s.into();
+        let bad = || {
+            s.error(format!(
+                "Expected '<name>: a-b or c-d', found '{}'",
+                s.as_str()
+            ))
+        };
+        let (name, limits) = s.split_once(": ").ok_or_else(bad)?;
+        let (lower, upper) = limits.split_once(" or ").ok_or_else(bad)?;
+
+        let range = |r: Text| -> Result<(u32, u32)> {
+            let (low, high) = r.split_once("-").ok_or_else(bad)?;
+            Ok((low.num()?, high.num()?))
+        };
+
+        Ok(Constraint {
+            name: name.as_str().to_owned(),
+            lower_group: range(lower)?,
+            upper_group: range(upper)?,
+        })
+    }
+}
+
+impl FromStr for Constraint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Constraint::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DaySixteen::parse("class: 1-3 or 5-7\nrow: 6-11 0-1")
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+        let error = DaySixteen::parse("class: 1-3 or 5-x").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 1, column 17: Expected a number, found 'x'"
+        );
+
+        let input = "class: 1-3 or 5-7\n\nyour ticket:\n7,1\n\nnearby tickets:\n7,3,47";
+        let error = DaySixteen::parse(input).err().unwrap();
+        assert!(error.to_string() == "Parse error at line 7: Expected 2 fields, found 3");
+
+        // Invalid values that add up to more than a u32
+        let input =
+            "class: 1-3 or 5-7\n\nyour ticket:\n1\n\nnearby tickets:\n4294967295\n4294967295";
+        let day = DaySixteen::parse(input).unwrap();
+        assert!(day.part_one().unwrap() == 2 * 4294967295);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::Result;
+use crate::grid::Sparse;
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub struct DaySeventeen {
+    // The active cells in the starting slice
+    active: Vec<(isize, isize)>,
+}
+
+impl Solution for DaySeventeen {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let slice = Text::new(input).grid(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let active = slice
+            .iter()
+            .filter(|(_, &active)| active)
+            .map(|(point, _)| point)
+            .collect();
+
+        Ok(DaySeventeen { active })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        let cubes = self.active.iter().map(|&(x, y)| (x, y, 0)).collect();
+        Ok(boot(cubes, get_neighbours))
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        let cubes = self.active.iter().map(|&(x, y)| (x, y, 0, 0)).collect();
+        Ok(boot(cubes, get_neighbours_4))
+    }
+}
+
+// Six cycles, in however many dimensions the cubes have
+fn boot<P: Copy + Eq + Hash>(mut cubes: Sparse<P>, neighbours: fn(P) -> HashSet<P>) -> usize {
+    for _ in 0..6 {
+        cubes = cubes.step(neighbours, |active, n| n == 3 || (active && n == 2));
+    }
+    cubes.len()
+}
+
+fn get_neighbours(coords: (isize, isize, isize)) -> HashSet<(isize, isize, isize)> {
+    let mut neighbours: HashSet<(isize, isize, isize)> = HashSet::new();
+    let x = coords.0;
+    let y = coords.1;
+    let z = coords.2;
+
+    // ring of 8 around you, and up & down
+    for nz in z - 1..z + 1 + 1 {
+        for (nx, ny) in &[
+            (x - 1, y - 1),
+            (x, y - 1),
+            (x + 1, y - 1),
+            (x - 1, y),
+            (x + 1, y),
+            (x - 1, y + 1),
+            (x, y + 1),
+            (x + 1, y + 1),
+        ] {
+            neighbours.insert((*nx, *ny, nz));
+        }
+    }
+
+    // And 1 above, and 1 below
+    neighbours.insert((x, y, z - 1));
+    neighbours.insert((x, y, z + 1));
+
+    neighbours
+}
+
+fn get_neighbours_4(coords: (isize, isize, isize, isize)) -> HashSet<(isize, isize, isize, isize)> {
+    let mut neighbours: HashSet<(isize, isize, isize, isize)> = HashSet::new();
+    let x = coords.0;
+    let y = coords.1;
+    let z = coords.2;
+    let w = coords.3;
+
+    let range = [-1, 0, 1];
+
+    for &dx in &range {
+        for &dy in &range {
+            for &dz in &range {
+                for &dw in &range {
+                    if !(dx == 0 && dy == 0 && dz == 0 && dw == 0) {
+                        neighbours.insert((x + dx, y + dy, z + dz, w + dw));
+                    }
+                }
+            }
+        }
+    }
+
+    neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        assert!(get_neighbours((0, 0, 0)).len() == 26);
+        assert!(get_neighbours((1, 2, 3)).contains(&(2, 2, 2)));
+        assert!(get_neighbours((1, 2, 3)).contains(&(0, 2, 3)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(DaySeventeen::parse(".#.\n..#\n#?#").is_err());
+    }
+
+    #[test]
+    fn test_neighbours_4() {
+        assert!(get_neighbours_4((0, 0, 0, 0)).len() == 80);
+        assert!(get_neighbours_4((1, 2, 3, 4)).contains(&(2, 2, 3, 3)));
+        assert!(get_neighbours_4((1, 2, 3, 4)).contains(&(0, 2, 3, 4)));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::parse_num;
+use crate::solution::Solution;
+
+pub struct DayEighteen {
+    expressions: Vec<Expression>,
+}
+
+impl Solution for DayEighteen {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayEighteen {
+            expressions: Text::new(input).parse_lines(Expression::parse)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        self.sum(false)
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        self.sum(true)
+    }
+}
+
+impl DayEighteen {
+    fn sum(&self, with_precedence: bool) -> Result<u64> {
+        self.expressions.iter().try_fold(0u64, |total, expression| {
+            total
+                .checked_add(expression.eval(with_precedence)?)
+                .ok_or_else(too_big)
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Number(u64),
+    Op(Op),
+    Open,
+    Close,
+}
+
+/// One line of homework, e.g. "1 + (2 * 3)", checked to be well formed
+pub struct Expression(Vec<Token>);
+
+impl Expression {
+    pub fn parse<'a>(line: impl Into<Text<'a>>) -> Result<Expression> {
+        let line = line.into();
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut open: Vec<Text> = Vec::new(); // Each bracket we're inside
+        let mut want_value = true;
+
+        let mut chars = line.chars().peekable();
+        while let Some((at, c)) = chars.next() {
+            let token = match (want_value, c) {
+                (_, c) if c.is_whitespace() => continue,
+                (true, '(') => {
+                    open.push(at);
+                    Token::Open
+                }
+                (true, '0'..='9') => {
+                    // The rest of the number
+                    let mut digits = c.to_string();
+                    while let Some(&(_, digit @ '0'..='9')) = chars.peek() {
+                        digits.push(digit);
+                        chars.next();
+                    }
+                    Token::Number(parse_num(&digits).map_err(|e| at.locate(e))?)
+                }
+                (false, ')') => match open.pop() {
+                    Some(_) => Token::Close,
+                    None => return Err(at.error("Unbalanced parentheses")),
+                },
+                (false, '+') => Token::Op(Op::Add),
+                (false, '*') => Token::Op(Op::Multiply),
+                (true, unexpected) => {
+                    return Err(at.error(format!("Expected a number, found '{}'", unexpected)))
+                }
+                (false, unexpected) => {
+                    return Err(at.error(format!("Expected +, * or ), found '{}'", unexpected)))
+                }
+            };
+            want_value = matches!(token, Token::Open | Token::Op(_));
+            tokens.push(token);
+        }
+
+        // Ended on an operator, or had nothing in it at all
+        if want_value {
+            return Err(line.error(format!("Missing value at end of '{}'", line.as_str())));
+        }
+        if let Some(bracket) = open.last() {
+            return Err(bracket.error("Unbalanced parentheses"));
+        }
+        Ok(Expression(tokens))
+    }
+
+    /// Without precedence, + and * are evaluated left to right; with it, +
+    /// binds tighter than *.  It's an error if a value is too big to hold.
+    pub fn eval(&self, with_precedence: bool) -> Result<u64> {
+        // The chain of each bracket we're inside, and the op before the bracket
+        let mut outer: Vec<(Vec<(Op, u64)>, Op)> = Vec::new();
+        let mut chain: Vec<(Op, u64)> = Vec::new();
+        let mut op = Op::Add; // First op is "+ (first number)"
+
+        for &token in &self.0 {
+            match token {
+                Token::Number(value) => chain.push((op, value)),
+                Token::Op(next) => op = next,
+                Token::Open => {
+                    outer.push((std::mem::take(&mut chain), op));
+                    op = Op::Add;
+                }
+                Token::Close => {
+                    // Parsing checked the brackets match
+                    let (mut enclosing, before) = outer.pop().unwrap();
+                    enclosing.push((before, combine(&chain, with_precedence)?));
+                    chain = enclosing;
+                }
+            }
+        }
+        combine(&chain, with_precedence)
+    }
+}
+
+/// Evaluate one expression
+pub fn eval<'a>(line: impl Into<Text<'a>>, with_precedence: bool) -> Result<u64> {
+    Expression::parse(line)?.eval(with_precedence)
+}
+
+// The value of a chain of ops, each applied to the result so far
+fn combine(chain: &[(Op, u64)], with_precedence: bool) -> Result<u64> {
+    match with_precedence {
+        true => {
+            // collect into chains of addition, split by multiplies
+            let mut chainz: Vec<Vec<u64>> = Vec::new();
+            let mut addz: Vec<u64> = Vec::new();
+            for (op, value) in chain.iter() {
+                match op {
+                    Op::Add => addz.push(*value),
+                    Op::Multiply => {
+                        chainz.push(addz);
+                        addz = vec![*value];
+                    }
+                }
+            }
+            chainz.push(addz);
+
+            chainz
+                .iter()
+                .try_fold(1u64, |macc, adds| {
+                    let sum = adds.iter().try_fold(0u64, |acc, &v| acc.checked_add(v))?;
+                    macc.checked_mul(sum)
+                })
+                .ok_or_else(too_big)
+        }
+        false => chain
+            .iter()
+            .try_fold(0, |acc, (op, val)| op.combine(acc, *val))
+            .ok_or_else(too_big),
+    }
+}
+
+fn too_big() -> Error {
+    Error::unsupported("a value is too big for a u64")
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Multiply,
+}
+
+impl Op {
+    fn combine(&self, a: u64, b: u64) -> Option<u64> {
+        match self {
+            Op::Add => a.checked_add(b),
+            Op::Multiply => a.checked_mul(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parens() {
+        assert!(eval("5", false).unwrap() == 5);
+        assert!(eval("5 + 2", false).unwrap() == 7);
+        assert!(eval("(5)", false).unwrap() == 5);
+        assert!(eval("((5))", false).unwrap() == 5);
+        assert!(eval("(((5)) + (1 * (2)))", false).unwrap() == 7);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(eval("(5 + 2", false).is_err());
+        assert!(eval("5 +", false).is_err());
+        assert!(eval("5 - 2", false).is_err());
+        assert!(eval("()", false).is_err());
+        assert!(eval("1 2", false).is_err());
+        assert!(eval("1 + é", false).is_err());
+        assert!(eval("1 + 2)", false).is_err());
+
+        // Values too big for a u64
+        let big = "9999999999 * 9999999999";
+        assert!(matches!(eval(big, false), Err(Error::Unsupported(_))));
+        assert!(matches!(eval(big, true), Err(Error::Unsupported(_))));
+        let big = "18446744073709551615 + (1 * 1)";
+        assert!(matches!(eval(big, false), Err(Error::Unsupported(_))));
+        assert!(matches!(eval(big, true), Err(Error::Unsupported(_))));
+        let day = DayEighteen::parse("18446744073709551615\n1").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+
+        let error = DayEighteen::parse("1 + 2\n3 * x").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 2, column 5: Expected a number, found 'x'"
+        );
+        // Blank lines still count
+        let error = DayEighteen::parse("1 + 2\n\n3 + x").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 3, column 5: Expected a number, found 'x'"
+        );
+        let error = DayEighteen::parse("1 + 2\n\n(3 * (4 + 5)").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 3, column 1: Unbalanced parentheses");
+        let error = DayEighteen::parse("1 + 2\n\n3 + 4)").err().unwrap();
+        assert!(error.to_string() == "Parse error at line 3, column 6: Unbalanced parentheses");
+        let error = DayEighteen::parse("2 * é").err().unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 1, column 5: Expected a number, found 'é'"
+        );
+    }
+
+    #[test]
+    fn test_one() {
+        assert!(eval("1 + 2 * 3 + 4 * 5 + 6", false).unwrap() == 71);
+        assert!(eval("1 + (2 * 3) + (4 * (5 + 6))", false).unwrap() == 51);
+        assert!(eval("2 * 3 + (4 * 5)", false).unwrap() == 26);
+        assert!(eval("5 + (8 * 3 + 9 + 3 * 4 * 3)", false).unwrap() == 437);
+        assert!(eval("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", false).unwrap() == 12240);
+        assert!(eval("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", false).unwrap() == 13632);
+    }
+
+    #[test]
+    fn test_two() {
+        assert!(eval("1 + 2 * 3 + 4 * 5 + 6", true).unwrap() == 231);
+        assert!(eval("1 + (2 * 3) + (4 * (5 + 6))", true).unwrap() == 51);
+        assert!(eval("2 * 3 + (4 * 5)", true).unwrap() == 46);
+        assert!(eval("5 + (8 * 3 + 9 + 3 * 4 * 3)", true).unwrap() == 1445);
+        assert!(eval("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", true).unwrap() == 669060);
+        assert!(eval("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", true).unwrap() == 23340);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct DayNineteen {
+    partial_rules: HashMap<u32, String>,
+    finished_rules: HashMap<u32, String>,
+    messages: Vec<String>,
+}
+
+impl Solution for DayNineteen {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        // Rules, then a blank line, then the messages to check
+        let (rules, messages) = match Text::new(input).blocks()[..] {
+            [rules] => (rules, Vec::new()),
+            [rules, messages] => (rules, messages.lines()),
+            ref blocks => {
+                return Err(Error::parse(format!(
+                    "Expected rules and messages, found {} blocks of lines",
+                    blocks.len()
+                )))
+            }
+        };
+        let (partial_rules, finished_rules) = build_rules(rules)?;
+        let messages = messages
+            .iter()
+            .map(|message| message.as_str().to_string())
+            .collect();
+
+        Ok(DayNineteen {
+            partial_rules,
+            finished_rules,
+            messages,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        part_one(
+            &self.partial_rules,
+            self.finished_rules.clone(),
+            &self.messages,
+        )
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        part_two(
+            &self.partial_rules,
+            self.finished_rules.clone(),
+            &self.messages,
+        )
+    }
+}
+
+fn part_one(
+    partial_rules: &HashMap<u32, String>,
+    mut finished_rules: HashMap<u32, String>,
+    messages: &[String],
+) -> Result<usize> {
+    let pattern: &str = &format!("^{}$", rule_for_val(0, partial_rules, &mut finished_rules)?);
+    solve(messages, pattern)
+}
+
+fn part_two(
+    partial_rules: &HashMap<u32, String>,
+    mut finished_rules: HashMap<u32, String>,
+    messages: &[String],
+) -> Result<usize> {
+    let rule_42 = rule_for_val(42, partial_rules, &mut finished_rules)?;
+    let rule_31 = rule_for_val(31, partial_rules, &mut finished_rules)?;
+
+    // 8 = "42 | 42 8" => "42+"
+    let mut rule_8 = "((".to_string();
+    rule_8.push_str(&rule_42);
+    rule_8.push_str(")+)");
+    finished_rules.insert(8, rule_8);
+
+    // 11: 42 31 | 42 11 31 => AB|AABB|AAABBB|...
+    // Not possible with a regular expression 😲
+    // Just build up to a sensible-looking limit.
+    let mut rule_11 = "(".to_string();
+    rule_11.push_str(&rule_42);
+    rule_11.push_str(&rule_31);
+    for repeats in 2..5 {
+        rule_11.push('|');
+        for _ in 0..repeats {
+            rule_11.push_str(&rule_42);
+        }
+        for _ in 0..repeats {
+            rule_11.push_str(&rule_31);
+        }
+    }
+    rule_11.push(')');
+    finished_rules.insert(11, rule_11);
+
+    let pattern: &str = &format!("^{}$", rule_for_val(0, partial_rules, &mut finished_rules)?);
+    solve(messages, pattern)
+}
+
+type Rules = (HashMap<u32, String>, HashMap<u32, String>);
+
+fn build_rules(rules: Text) -> Result<Rules> {
+    let mut partial_rules: HashMap<u32, String> = HashMap::new();
+    let mut finished_rules: HashMap<u32, String> = HashMap::new();
+
+    for line in rules.lines() {
+        let (number, rule) = line.split_once(": ").ok_or_else(|| {
+            line.error(format!(
+                "Expected '<number>: <rule>', found '{}'",
+                line.as_str()
+            ))
+        })?;
+        let number: u32 = number.num()?;
+
+        match rule.as_str() {
+            "\"a\"" => finished_rules.insert(number, "a".to_string()),
+            "\"b\"" => finished_rules.insert(number, "b".to_string()),
+            _ => {
+                for elem in rule.split(" ").iter().filter(|elem| elem.as_str() != "|") {
+                    elem.num::<u32>()?;
+                }
+                partial_rules.insert(number, rule.as_str().to_string())
+            }
+        };
+    }
+
+    Ok((partial_rules, finished_rules))
+}
+
+fn solve(messages: &[String], pattern: &str) -> Result<usize> {
+    let re = Regex::new(pattern).map_err(|e| Error::unsupported(e.to_string()))?;
+    Ok(messages.iter().filter(|s| re.is_match(s)).count())
+}
+
+lazy_static! {
+    static ref HAS_NUMBERS: Regex = Regex::new(r"[0-9]+").unwrap();
+}
+
+fn rule_for_val(
+    number: u32,
+    partials: &HashMap<u32, String>,
+    finished: &mut HashMap<u32, String>,
+) -> Result<String> {
+    expand(number, partials, finished, &mut Vec::new())
+}
+
+// The rule as a regex, with the rules it's already inside `expanding` - a
+// rule inside itself would never finish
+fn expand(
+    number: u32,
+    partials: &HashMap<u32, String>,
+    finished: &mut HashMap<u32, String>,
+    expanding: &mut Vec<u32>,
+) -> Result<String> {
+    if expanding.contains(&number) {
+        return Err(Error::unsupported(format!(
+            "rule {} refers back to itself",
+            number
+        )));
+    }
+
+    match finished.get(&number) {
+        Some(rule) => Ok(rule.to_string()),
+        None => {
+            let partial = partials
+                .get(&number)
+                .ok_or_else(|| Error::unsupported(format!("no rule {}", number)))?;
+            expanding.push(number);
+            let mut rule = "(".to_string();
+            for elem in partial.split(' ') {
+                match elem {
+                    "|" => rule.push('|'),
+                    num => {
+                        // Checked when the rules were parsed
+                        let num = num.parse::<u32>().unwrap();
+                        let sub_rule = expand(num, partials, finished, expanding)?;
+
+                        if !HAS_NUMBERS.is_match(&sub_rule) {
+                            finished.insert(num, sub_rule.to_owned());
+                        }
+
+                        rule.push_str(&sub_rule);
+                    }
+                };
+            }
+            rule.push(')');
+            expanding.pop();
+
+            Ok(rule)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayNineteen::parse("0: 1 2\n1: \"a\"\n2: 1 x")
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string() == "Parse error at line 3, column 6: Expected a number, found 'x'"
+        );
+
+        let day = DayNineteen::parse("0: 1 2\n1: \"a\"\n\naa").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+
+        // Rules that loop back on themselves
+        let day = DayNineteen::parse("0: 1\n1: 0\n\na").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+        let day = DayNineteen::parse("0: 1 2\n1: \"a\"\n2: 1 | 2 1\n\naa").unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+        let day = DayNineteen::parse("0: 1 1\n1: \"a\"\n\naa").unwrap();
+        assert!(day.part_one().unwrap() == 1);
+    }
+}
@@ -1,16 +1,51 @@
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Point};
+use crate::parse::Text;
+use crate::solution::Solution;
 use lazy_static::lazy_static;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::sync::Mutex;
-use std::{fmt, fs};
 
 const TILE_WIDTH: usize = 10;
 
-pub(crate) fn part_one(filename: &str) -> u64 {
-    SolvedMosaic::solve(filename).part_one_value()
+// Sea monsters look like this, in any orientation
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+pub struct DayTwenty {
+    tiles: Vec<Tile>,
+}
+
+impl DayTwenty {
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
 }
 
-pub(crate) fn part_two(filename: &str) -> usize {
-    SolvedMosaic::solve(filename).part_two_value()
+impl Solution for DayTwenty {
+    type PartOne = u64;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut tiles: Vec<Tile> = Vec::new();
+        for definition in Text::new(input).blocks() {
+            tiles.push(Tile::from(definition)?);
+        }
+
+        Ok(DayTwenty { tiles })
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        Ok(SolvedMosaic::solve(&self.tiles)?.part_one_value())
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(SolvedMosaic::solve(&self.tiles)?.part_two_value())
+    }
 }
 
 struct SolvedMosaic {
@@ -19,45 +54,32 @@ struct SolvedMosaic {
 }
 
 impl SolvedMosaic {
-    fn solve(filename: &str) -> SolvedMosaic {
-        let tiles: Vec<Tile> = fs::read_to_string(filename)
-            .unwrap()
-            .split("\n\n")
-            .map(|s| Tile::from(s))
-            .collect();
-
+    fn solve(tiles: &[Tile]) -> Result<SolvedMosaic> {
         let mut tile_to_tiles: HashMap<u64, Tile> = HashMap::new();
         let mut edge_to_tiles: HashMap<u16, HashSet<u64>> = HashMap::new();
-        for tile in &tiles {
+        for tile in tiles {
             tile_to_tiles.insert(tile.id, tile.clone());
 
             for edge in tile.possible_edges.iter() {
-                edge_to_tiles
-                    .entry(*edge)
-                    .or_insert_with(HashSet::new)
-                    .insert(tile.id);
+                edge_to_tiles.entry(*edge).or_default().insert(tile.id);
             }
         }
 
-        let mut mosaic = match tiles.len() {
-            144 => Mosaic {
-                len: 12,
-                tile_to_tiles,
-            },
-            9 => Mosaic {
-                len: 3,
-                tile_to_tiles,
-            },
-            oops => panic!("Unexpected number of tiles: {:}", oops),
-        };
-
-        for tile in &tiles {
-            if let Some(solution) = mosaic.solve_from(&tile) {
-                return solution;
+        // The tiles have to make a square
+        let len = (1..=tiles.len())
+            .find(|len| len * len == tiles.len())
+            .ok_or_else(|| {
+                Error::unsupported(format!("{} tiles don't make a square", tiles.len()))
+            })?;
+        let mut mosaic = Mosaic { len, tile_to_tiles };
+
+        for tile in tiles {
+            if let Some(solution) = mosaic.solve_from(tile) {
+                return Ok(solution);
             }
         }
 
-        panic!("Couldn't find a solution");
+        Err(Error::no_solution("the tiles don't fit together"))
     }
 
     fn part_one_value(&self) -> u64 {
@@ -68,129 +90,71 @@ impl SolvedMosaic {
     }
 
     fn part_two_value(&self) -> usize {
-        // Stitch the tiles into a picture
-        let mut picture: Vec<u128> = Vec::new();
-        for tile_row in &self.tiles {
-            // Trim off both top & bottom, and left & right
-            for y in 1..(TILE_WIDTH - 1) {
-                picture.push(tile_row.iter().enumerate().fold(0, |acc, (i, tile)| {
-                    acc | (((tile.contents[y] as u128 >> 1) & 0b1111_1111)
-                        << ((TILE_WIDTH - 2) * (self.len - 1 - i)))
-                }));
+        // Stitch the tiles into a picture, trimming off their borders
+        let width = TILE_WIDTH - 2;
+        let mut picture = Grid::new(self.len * width, self.len * width, false);
+        for (tile_y, tile_row) in self.tiles.iter().enumerate() {
+            for (tile_x, tile) in tile_row.iter().enumerate() {
+                for ((x, y), &pixel) in tile.pixels.iter() {
+                    let (x, y) = (x as usize, y as usize);
+                    if (1..=width).contains(&x) && (1..=width).contains(&y) {
+                        let point = (tile_x * width + x - 1, tile_y * width + y - 1);
+                        *picture
+                            .get_mut((point.0 as isize, point.1 as isize))
+                            .unwrap() = pixel;
+                    }
+                }
             }
         }
 
         // (maybe print it, for fun!)
-        for x in &picture {
-            let width = self.len * (TILE_WIDTH - 2);
-            println!("{:0width$b}", x, width = width);
-        }
-
-        let mut picture = Picture { pic: picture };
-
-        // Find & count all the monsters - in all orientations of the picture
-        let mut all_orientations: Vec<Picture> = Vec::new();
-        all_orientations.push(picture.clone());
-        for _ in 0..3 {
-            picture.rotate();
-            all_orientations.push(picture.clone());
-        }
-        picture.flip();
-        all_orientations.push(picture.clone());
-        for _ in 0..3 {
-            picture.rotate();
-            all_orientations.push(picture.clone());
-        }
-        assert!(all_orientations.len() == 8);
+        verbose!("{}", picture);
 
+        // Find & count all the monsters - in all orientations of the picture.
         // Count all the '#', take off the monsters => answer
-        picture.count_bits()
-            - all_orientations
+        let pixels = picture.iter().filter(|(_, &pixel)| pixel).count();
+        pixels
+            - picture
+                .orientations()
                 .iter()
-                .fold(0, |max, pic| std::cmp::max(max, pic.count_monster_bits()))
+                .map(count_monster_pixels)
+                .max()
+                .unwrap()
     }
 }
 
-#[derive(Clone)]
-struct Picture {
-    pic: Vec<u128>,
-}
-
-impl Picture {
-    fn count_bits(&self) -> usize {
-        let x = self.pic.iter().fold(0, |acc, row| {
-            let mut n_bits = 0;
-            let mut number: u128 = *row;
-            while number > 0 {
-                n_bits += (number & 1) as usize;
-                number >>= 1;
-            }
-            acc + n_bits
-        });
-        // println!("Bits in picture: {:}", x);
-        x
+fn count_monster_pixels(picture: &Grid<bool>) -> usize {
+    let monster: Vec<Point> = MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (x as isize, y as isize))
+        })
+        .collect();
+    let (length, height) = (MONSTER[0].len(), MONSTER.len());
+    if picture.width() < length || picture.height() < height {
+        return 0;
     }
 
-    fn count_monster_bits(&self) -> usize {
-        // Find all the monsters in the picture
-        // <                  # >
-        // <#    ##    ##    ###>
-        // < #  #  #  #  #  #   >
-        let monster_parts: Vec<u128> = vec![
-            0b00000000000000000010,
-            0b10000110000110000111,
-            0b01001001001001001000,
-        ];
-        let monster_length: usize = 20; // From tip to tail
-        let bits_in_monster: usize = 15; // Could calculate this, but I just counted
-
-        // Count the monsters
-        let mut number_of_monsters = 0;
-
-        let width = self.pic.len();
-        for row_id in 0..width - 2 {
-            // Monster is 3 lines long, so stop before the end
-            for pos in 0..(width - monster_length) {
-                if let true = (0..3).all(|i| {
-                    // Head, body & legs must all match
-                    let mask = monster_parts[i] << (pos as u128);
-                    (self.pic[row_id + i] & mask) == mask
-                }) {
-                    number_of_monsters += 1;
-                    // Could skip forward a bit now, but no real need
-                }
+    let mut number_of_monsters = 0;
+    for y in 0..=(picture.height() - height) as isize {
+        for x in 0..=(picture.width() - length) as isize {
+            // Head, body & legs must all match
+            if monster
+                .iter()
+                .all(|(dx, dy)| picture.get((x + dx, y + dy)) == Some(&true))
+            {
+                number_of_monsters += 1;
+                // Could skip forward a bit now, but no real need
             }
         }
-
-        // println!("Found {:} monsters", number_of_monsters);
-        number_of_monsters * bits_in_monster
-    }
-
-    fn rotate(&mut self) {
-        // Anti-clockwise
-        let mut new_pic: Vec<u128> = Vec::new();
-
-        let width = self.pic.len(); // It's a square
-        for shift in 0..width {
-            new_pic.push(Picture::get_right_128(
-                &self.pic.iter().map(|&u| u >> shift).collect::<Vec<u128>>(),
-                width,
-            ));
-        }
-
-        self.pic = new_pic;
-    }
-
-    fn get_right_128(array: &[u128], width: usize) -> u128 {
-        array
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (i, b)| acc | ((b & 1) << (width - 1 - i)))
     }
 
-    fn flip(&mut self) {
-        self.pic.reverse();
-    }
+    // println!("Found {:} monsters", number_of_monsters);
+    number_of_monsters * monster.len()
 }
 
 struct Mosaic {
@@ -237,8 +201,8 @@ impl Mosaic {
 
     fn complete_picture(
         &mut self,
-        mut rows: &mut Vec<Vec<Tile>>,
-        mut remaining_tiles: &mut HashSet<u64>,
+        rows: &mut Vec<Vec<Tile>>,
+        remaining_tiles: &mut HashSet<u64>,
     ) -> Option<SolvedMosaic> {
         if remaining_tiles.is_empty() {
             // The picture is complete!
@@ -331,7 +295,7 @@ impl Mosaic {
             }
             remaining_tiles.remove(&candidate.id);
 
-            if let Some(answer) = self.complete_picture(&mut rows, &mut remaining_tiles) {
+            if let Some(answer) = self.complete_picture(rows, remaining_tiles) {
                 return Some(answer);
             }
 
@@ -346,31 +310,65 @@ impl Mosaic {
     }
 }
 
+/// One square of the image, with its borders
 #[derive(Clone, Eq)]
-struct Tile {
-    id: u64,
+pub struct Tile {
+    pub id: u64,
 
-    // Will change as tile is flipped/rotated
-    // top/bottom both read left-to-right; left, right both read top-to-bottom
-    // This means if A stacks on B then A.bottom()==B.top() etc.
-    contents: Vec<u16>,
+    // Will change as tile is flipped/rotated.  Edges are read as binary,
+    // '#' for 1: top/bottom both read left-to-right; left, right both read
+    // top-to-bottom.  This means if A stacks on B then A.bottom()==B.top() etc.
+    pixels: Grid<bool>,
 
     // All possible edges
     possible_edges: HashSet<u16>,
 }
 
 impl Tile {
-    fn from(definition: &str) -> Tile {
-        let mut lines = definition.lines();
-        let id = lines.next().unwrap()[5..9].parse::<u64>().unwrap();
-
-        // let chars: Vec<Vec<char>> = lines.map(|s| s.chars().collect()).collect();
-
-        let contents: Vec<u16> = lines.map(|s| Tile::line_to_u16(s)).collect();
-        let top = contents[0];
-        let bottom = *contents.last().unwrap();
-        let left = Tile::get_left(&contents);
-        let right = Tile::get_right(&contents);
+    // The tile starts on line `first_line` of the input, for error messages
+    pub fn from<'a>(definition: impl Into<Text<'a>>) -> Result<Tile> {
+        let definition = definition.into();
+        let header = *definition
+            .lines()
+            .first()
+            .ok_or_else(|| definition.error("Expected a tile"))?;
+        let id = header
+            .strip_prefix("Tile ")
+            .and_then(|s| s.strip_suffix(":"))
+            .ok_or_else(|| {
+                header.error(format!(
+                    "Expected 'Tile <id>:', found '{}'",
+                    header.as_str()
+                ))
+            })?
+            .num()?;
+
+        let (_, body) = definition
+            .split_once("\n")
+            .ok_or_else(|| header.error(format!("Tile {} has no pixels", id)))?;
+        let pixels = body.grid(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if pixels.width() != TILE_WIDTH || pixels.height() != TILE_WIDTH {
+            return Err(header.error(format!(
+                "Tile {} is {}x{}, expected {}x{}",
+                id,
+                pixels.width(),
+                pixels.height(),
+                TILE_WIDTH,
+                TILE_WIDTH
+            )));
+        }
+        let (top, bottom) = (
+            edge(pixels.row(0)),
+            edge(pixels.row(TILE_WIDTH as isize - 1)),
+        );
+        let (left, right) = (
+            edge(pixels.column(0)),
+            edge(pixels.column(TILE_WIDTH as isize - 1)),
+        );
 
         let mut possible_edges: HashSet<u16> = HashSet::new();
         for edge in &[top, bottom, left, right] {
@@ -378,42 +376,32 @@ impl Tile {
             possible_edges.insert(reverse_10_bits(*edge));
         }
 
-        assert!(possible_edges.len() == 8); // A bit of a hack - ensures there's a unique way to rotate & match an edge
+        // A bit of a hack - ensures there's a unique way to rotate & match an edge
+        if possible_edges.len() != 8 {
+            return Err(Error::unsupported(format!(
+                "tile {} has symmetric edges",
+                id
+            )));
+        }
 
-        Tile {
+        Ok(Tile {
             id,
-            contents,
+            pixels,
             possible_edges,
-        }
-    }
-
-    fn line_to_u16(s: &str) -> u16 {
-        u16::from_str_radix(&s.replace(".", "0").replace("#", "1"), 2).unwrap()
+        })
     }
 
-    fn rotate(&mut self) {
+    pub fn rotate(&mut self) {
         // Anti-clockwise
-        let mut new_contents: Vec<u16> = Vec::new();
-
-        for shift in 0..TILE_WIDTH {
-            new_contents.push(Tile::get_right(
-                &self
-                    .contents
-                    .iter()
-                    .map(|&u| u >> shift)
-                    .collect::<Vec<u16>>(),
-            ));
-        }
-
-        self.contents = new_contents;
+        self.pixels.rotate();
     }
 
-    fn flip(&mut self) {
+    pub fn flip(&mut self) {
         // About the horizontal axis
-        self.contents.reverse();
+        self.pixels.flip();
     }
 
-    fn turn_to_top(&mut self, val: u16) {
+    pub fn turn_to_top(&mut self, val: u16) {
         // Flip/ rotate until the top is the value we want
         for _turn in 0..4 {
             if self.top() == val {
@@ -444,44 +432,31 @@ impl Tile {
         );
     }
 
-    fn turn_to_left(&mut self, val: u16) {
+    pub fn turn_to_left(&mut self, val: u16) {
         self.turn_to_top(reverse_10_bits(val)); // Top to side is flipped on rotate, because of how I've defined the reading direction
         self.rotate();
     }
 
-    fn top(&self) -> u16 {
-        self.contents[0]
-    }
-
-    fn bottom(&self) -> u16 {
-        *self.contents.last().unwrap()
-    }
-
-    fn left(&self) -> u16 {
-        Tile::get_left(&self.contents)
+    pub fn top(&self) -> u16 {
+        edge(self.pixels.row(0))
     }
 
-    fn right(&self) -> u16 {
-        Tile::get_right(&self.contents)
+    pub fn bottom(&self) -> u16 {
+        edge(self.pixels.row(TILE_WIDTH as isize - 1))
     }
 
-    fn get_left(contents: &[u16]) -> u16 {
-        Tile::get_edge(contents, true)
+    pub fn left(&self) -> u16 {
+        edge(self.pixels.column(0))
     }
 
-    fn get_right(contents: &[u16]) -> u16 {
-        Tile::get_edge(contents, false)
+    pub fn right(&self) -> u16 {
+        edge(self.pixels.column(TILE_WIDTH as isize - 1))
     }
+}
 
-    fn get_edge(contents: &[u16], left_not_right: bool) -> u16 {
-        let shift = match left_not_right {
-            true => TILE_WIDTH - 1,
-            false => 0,
-        };
-        contents.iter().enumerate().fold(0, |acc, (i, b)| {
-            acc | (((b & (1 << shift)) >> shift) << (TILE_WIDTH - 1 - i))
-        })
-    }
+// A row or column of pixels as a binary number, the first pixel highest
+fn edge<'a>(pixels: impl Iterator<Item = &'a bool>) -> u16 {
+    pixels.fold(0, |acc, &pixel| (acc << 1) | pixel as u16)
 }
 
 impl std::hash::Hash for Tile {
@@ -490,7 +465,6 @@ impl std::hash::Hash for Tile {
         H: std::hash::Hasher,
     {
         state.write_u64(self.id);
-        state.finish();
     }
 }
 
@@ -543,7 +517,7 @@ mod tests {
     fn test_reverse() {
         assert!(reverse_10_bits(0b1_000_000_000) == 1);
         assert!(reverse_10_bits(0b1_000_000_000) == 1); // Cached
-        assert!(reverse_10_bits(0b0_101_100_110) == 0b011_001_101_0);
+        assert!(reverse_10_bits(0b0_101_100_110) == 0b01_1001_1010);
     }
 
     #[test]
@@ -560,7 +534,8 @@ mod tests {
 ..#....#..
 ###...#.#.
 ..###..###",
-        );
+        )
+        .unwrap();
         assert!(tile.top() == 0b00110_10010);
         assert!(tile.left() == 0b01111_10010);
         assert!(tile.bottom() == 0b00111_00111);
@@ -589,12 +564,16 @@ mod tests {
     }
 
     #[test]
-    fn test_one() {
-        assert!(part_one("data/20_example.txt") == 20899048083289);
-    }
+    fn test_errors() {
+        let input = crate::read_file("data/2020/20_example.txt").unwrap();
+        let tiles: Vec<&str> = input.split("\n\n").collect();
 
-    #[test]
-    fn test_two() {
-        assert!(part_two("data/20_example.txt") == 273);
+        // 8 tiles can't make a square
+        let day = DayTwenty::parse(&tiles[..8].join("\n\n")).unwrap();
+        assert!(matches!(day.part_one(), Err(Error::Unsupported(_))));
+
+        let bad = tiles[..2].join("\n\n").replacen("Tile 1951", "Tile x", 1);
+        let error = DayTwenty::parse(&bad).err().unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(13), .. }));
     }
 }
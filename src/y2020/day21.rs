@@ -0,0 +1,159 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+use std::collections::{BTreeMap, HashSet};
+
+/// A food's ingredients, and the allergens it's known to contain
+pub type Food = (HashSet<String>, HashSet<String>);
+
+pub struct DayTwentyOne {
+    rows: Vec<Food>,
+}
+
+impl Solution for DayTwentyOne {
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayTwentyOne {
+            rows: Text::new(input).parse_lines(parse_food)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        let rows = &self.rows;
+        let allergens_to_poss_ingrs = get_possible_allergens(rows);
+        let may_contain_allergens = get_maybes(&allergens_to_poss_ingrs);
+
+        Ok(rows.iter().fold(0, |acc, row| {
+            acc + row.0.difference(&may_contain_allergens).count()
+        }))
+    }
+
+    fn part_two(&self) -> Result<String> {
+        part_two(&self.rows)
+    }
+}
+
+// e.g. "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"
+pub fn parse_food<'a>(row: impl Into<Text<'a>>) -> Result<Food> {
+    let row = row.into();
+    let (ingredients, allergen_list) = row
+        .split_once(" (contains ")
+        .and_then(|(i, a)| Some((i, a.strip_suffix(")")?)))
+        .ok_or_else(|| {
+            row.error(format!(
+                "Expected '<ingredients> (contains <allergens>)', found '{}'",
+                row.as_str()
+            ))
+        })?;
+    let ingredients: HashSet<String> = ingredients
+        .split(" ")
+        .iter()
+        .map(|s| s.as_str().to_string())
+        .collect();
+    let allergens: HashSet<String> = allergen_list
+        .split(", ")
+        .iter()
+        .map(|s| s.as_str().to_string())
+        .collect();
+
+    Ok((ingredients, allergens))
+}
+
+fn get_possible_allergens(rows: &[Food]) -> BTreeMap<String, HashSet<String>> {
+    let mut allergens_to_poss_ingrs: BTreeMap<String, HashSet<String>> = BTreeMap::new();
+    for row in rows.iter() {
+        let ingredients = &row.0;
+        let allergens = &row.1;
+        for allergen in allergens {
+            allergens_to_poss_ingrs.insert(
+                allergen.clone(),
+                match allergens_to_poss_ingrs.get(allergen) {
+                    Some(other_ingredients) => other_ingredients
+                        .intersection(ingredients)
+                        .map(|s| s.to_string())
+                        .collect(),
+                    None => ingredients.clone(),
+                },
+            );
+        }
+    }
+
+    allergens_to_poss_ingrs
+}
+
+fn get_maybes(allergens_to_poss_ingrs: &BTreeMap<String, HashSet<String>>) -> HashSet<String> {
+    allergens_to_poss_ingrs
+        .values()
+        .fold(HashSet::new(), |acc, v| {
+            acc.union(v).map(|s| s.to_string()).collect()
+        })
+}
+
+fn part_two(rows: &[Food]) -> Result<String> {
+    let mut allergens_to_poss_ingrs = get_possible_allergens(rows);
+
+    // println!("{:?}", allergens_to_poss_ingrs);
+
+    // Lazy - lifted from similar problem on day 16 (though that was HashSet, not Map)
+    loop {
+        if allergens_to_poss_ingrs.values().all(|c| c.len() == 1) {
+            break;
+        }
+
+        let uniques: HashSet<String> = allergens_to_poss_ingrs
+            .iter()
+            .filter(|(_k, v)| v.len() == 1)
+            .map(|(_k, v)| v.iter().next().unwrap().to_owned())
+            .collect();
+
+        // For sets that are not solved (len!=1), remove the solved ones
+        let remaining: usize = allergens_to_poss_ingrs.values().map(|v| v.len()).sum();
+        allergens_to_poss_ingrs = allergens_to_poss_ingrs
+            .into_iter()
+            .map(|(k, v)| match v.len() {
+                1 => (k, v),
+                _ => (k, v.difference(&uniques).map(|s| s.to_string()).collect()),
+            })
+            .collect();
+
+        // Stuck, or ruled out every ingredient for some allergen
+        if allergens_to_poss_ingrs
+            .values()
+            .map(|v| v.len())
+            .sum::<usize>()
+            == remaining
+            || allergens_to_poss_ingrs.values().any(|v| v.is_empty())
+        {
+            return Err(Error::no_solution(
+                "can't narrow down which ingredient has which allergen",
+            ));
+        }
+    }
+
+    // println!("{:?}", allergens_to_poss_ingrs);
+
+    Ok(allergens_to_poss_ingrs
+        .values()
+        .map(|v| v.iter().next().unwrap().to_string())
+        .collect::<Vec<String>>()
+        .join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayTwentyOne::parse("a b (contains dairy)\nc d (contains fish")
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+
+        // Either ingredient could be either allergen
+        let day = DayTwentyOne::parse("a b (contains dairy, fish)").unwrap();
+        assert!(matches!(day.part_two(), Err(Error::NoSolution(_))));
+    }
+}
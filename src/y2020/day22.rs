@@ -1,16 +1,68 @@
-use crate::file_to_vec;
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
 use std::collections::{HashSet, VecDeque};
 
-pub(crate) fn part_one(deck_one: &str, deck_two: &str) -> i32 {
-    let mut deck_one: VecDeque<i32> = file_to_vec(deck_one)
-        .iter()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-    let mut deck_two: VecDeque<i32> = file_to_vec(deck_two)
-        .iter()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
+pub struct DayTwentyTwo {
+    deck_one: VecDeque<i32>,
+    deck_two: VecDeque<i32>,
+}
+
+impl Solution for DayTwentyTwo {
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        // "Player 1:" and its deck, a blank line, then "Player 2:" and its deck
+        let (one, two) = match Text::new(input).blocks()[..] {
+            [one, two] => (one, two),
+            _ => {
+                return Err(Error::parse(
+                    "Expected two decks, separated by a blank line",
+                ))
+            }
+        };
+        let deck_one = parse_deck(one)?;
+        let deck_two = parse_deck(two)?;
+
+        let mut seen: HashSet<i32> = HashSet::new();
+        if let Some(card) = deck_one
+            .iter()
+            .chain(deck_two.iter())
+            .find(|&&c| !seen.insert(c))
+        {
+            return Err(Error::unsupported(format!("card {} appears twice", card)));
+        }
+
+        Ok(DayTwentyTwo { deck_one, deck_two })
+    }
+
+    fn part_one(&self) -> Result<i32> {
+        Ok(part_one(self.deck_one.clone(), self.deck_two.clone()))
+    }
+
+    fn part_two(&self) -> Result<i32> {
+        Ok(part_two(self.deck_one.clone(), self.deck_two.clone()))
+    }
+}
+
+fn parse_deck(deck: Text) -> Result<VecDeque<i32>> {
+    let mut cards: VecDeque<i32> = VecDeque::new();
+    for line in deck.lines() {
+        if line.as_str().starts_with("Player") {
+            continue;
+        }
+        let card = line.num()?;
+        if card <= 0 {
+            return Err(line.error(format!("Cards must be positive, found {}", card)));
+        }
+        cards.push_back(card);
+    }
+
+    Ok(cards)
+}
 
+fn part_one(mut deck_one: VecDeque<i32>, mut deck_two: VecDeque<i32>) -> i32 {
     while !deck_one.is_empty() && !deck_two.is_empty() {
         let one = deck_one.pop_front().unwrap();
         let two = deck_two.pop_front().unwrap();
@@ -24,7 +76,7 @@ pub(crate) fn part_one(deck_one: &str, deck_two: &str) -> i32 {
                 deck_two.push_back(two);
                 deck_two.push_back(one);
             }
-            _ => panic!("Cards must all be unique! {:} - {:}", one, two),
+            _ => unreachable!("Cards must all be unique! {:} - {:}", one, two),
         }
     }
 
@@ -46,16 +98,7 @@ enum Winner {
     Two,
 }
 
-pub(crate) fn part_two(deck_one: &str, deck_two: &str) -> i32 {
-    let mut deck_one: VecDeque<i32> = file_to_vec(deck_one)
-        .iter()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-    let mut deck_two: VecDeque<i32> = file_to_vec(deck_two)
-        .iter()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-
+fn part_two(mut deck_one: VecDeque<i32>, mut deck_two: VecDeque<i32>) -> i32 {
     let winning_deck = match play_recursively(&mut deck_one, &mut deck_two) {
         Winner::One => deck_one,
         Winner::Two => deck_two,
@@ -127,13 +170,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_one() {
-        assert!(part_one("data/22_ex1.txt", "data/22_ex2.txt") == 306);
-    }
-
-    #[test]
-    fn test_two() {
-        assert!(part_two("data/22_ex1.txt", "data/22_ex2.txt") == 291);
+    fn test_errors() {
+        let error = DayTwentyTwo::parse("Player 1:\n9\n2\n\nPlayer 2:\n5\nx")
+            .err()
+            .unwrap();
+        assert!(error.to_string() == "Parse error at line 7: Expected a number, found 'x'");
+        let error = DayTwentyTwo::parse("Player 1:\n9\n2\n\nPlayer 2:\n5\n2")
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::Unsupported(_)));
     }
 
     #[test]
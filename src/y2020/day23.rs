@@ -1,4 +1,63 @@
-pub(crate) fn part_one(input: &str, rounds: usize) -> String {
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::parse_num;
+use crate::solution::Solution;
+
+pub struct DayTwentyThree {
+    cups: String,
+    moves: usize, // For part one
+}
+
+impl Solution for DayTwentyThree {
+    type PartOne = String;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let cups = Text::new(input).trim();
+
+        // One of each cup, labelled 1 up to however many there are
+        if let Some((at, c)) = cups.chars().find(|&(_, c)| !c.is_ascii_digit()) {
+            return Err(at.error(format!("Unexpected char: {:?}", c)));
+        }
+        let cups = cups.as_str();
+        let mut labels: Vec<char> = cups.chars().collect();
+        labels.sort_unstable();
+        let expected: String = (1..=labels.len())
+            .filter_map(|n| std::char::from_digit(n as u32, 10))
+            .collect();
+        if labels.len() < 4 || labels.iter().collect::<String>() != expected {
+            return Err(Error::unsupported(format!(
+                "cups must be 1 to n, with 4 <= n <= 9; found '{}'",
+                cups
+            )));
+        }
+
+        Ok(DayTwentyThree {
+            cups: cups.to_string(),
+            moves: 100,
+        })
+    }
+
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "moves" => Ok(DayTwentyThree {
+                moves: parse_num(value)?,
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 23", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<String> {
+        Ok(part_one(&self.cups, self.moves))
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        Ok(part_two(&self.cups, 10_000_000, 1_000_000))
+    }
+}
+
+fn part_one(input: &str, rounds: usize) -> String {
     let start = input.chars().next().unwrap().to_digit(10).unwrap() as usize;
     let mut cups = array_of_nexts(input);
 
@@ -15,7 +74,7 @@ pub(crate) fn part_one(input: &str, rounds: usize) -> String {
     out
 }
 
-pub(crate) fn part_two(input: &str, rounds: usize, n_cups: usize) -> u64 {
+fn part_two(input: &str, rounds: usize, n_cups: usize) -> u64 {
     let start = input.chars().next().unwrap().to_digit(10).unwrap() as usize;
     let mut cups = array_of_nexts(input);
 
@@ -63,7 +122,7 @@ fn array_of_nexts(input: &str) -> Vec<usize> {
     next_cups
 }
 
-pub(crate) fn crab_cups(start_cup: usize, cups: &mut Vec<usize>, rounds: usize) {
+fn crab_cups(start_cup: usize, cups: &mut [usize], rounds: usize) {
     let len = cups.len() - 1;
     let mut current = start_cup;
 
@@ -121,10 +180,20 @@ mod tests {
     }
 
     #[test]
-    fn test_one() {
-        assert!(&part_one("389125467", 1) == "54673289");
-        assert!(&part_one("389125467", 10) == "92658374");
-        assert!(&part_one("389125467", 100) == "67384529");
+    fn test_errors() {
+        assert!(DayTwentyThree::parse("389125467\n").is_ok());
+        assert!(matches!(
+            DayTwentyThree::parse("38912546x"),
+            Err(Error::Parse { .. })
+        ));
+        assert!(matches!(
+            DayTwentyThree::parse("389125466"),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            DayTwentyThree::parse("12"),
+            Err(Error::Unsupported(_))
+        ));
     }
 
     #[test]
@@ -132,24 +201,4 @@ mod tests {
         assert!(part_two("389125467", 0, 1_000_000) == 2 * 5);
         assert!(part_two("389254671", 0, 1_000_000) == 10 * 11);
     }
-
-    // #[test]
-    fn _time() {
-        use std::time::Instant;
-        let all_turns = [30_000, 100_000]; // Linear on n-turns
-        let all_sizes = [1_000_000]; // [1_000, 10_000, 100_000];
-        for &turns in all_turns.iter() {
-            for &size in all_sizes.iter() {
-                let start = Instant::now();
-                let _ = part_two("389254671", turns, size); //1_000_000);
-                println!(
-                    "{:} turns at {:} size\t{:}",
-                    turns,
-                    size,
-                    start.elapsed().as_secs()
-                );
-            }
-        }
-        assert!(false); // Show test output in stdout
-    }
 }
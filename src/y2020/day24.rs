@@ -0,0 +1,117 @@
+use crate::error::{Error, Result};
+use crate::grid::{Point, Sparse, HEX};
+use crate::parse::Text;
+use crate::parse_num;
+use crate::solution::Solution;
+
+pub struct DayTwentyFour {
+    // The black tiles
+    floor: Sparse<Point>,
+    days: usize,
+}
+
+impl Solution for DayTwentyFour {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(DayTwentyFour {
+            floor: build_floor(input)?,
+            days: 100,
+        })
+    }
+
+    fn with_param(self, name: &str, value: &str) -> Result<Self> {
+        match name {
+            "days" => Ok(DayTwentyFour {
+                days: parse_num(value)?,
+                ..self
+            }),
+            _ => Err(Error::usage(format!("No parameter '{}' for day 24", name))),
+        }
+    }
+
+    fn part_one(&self) -> Result<usize> {
+        Ok(self.floor.len())
+    }
+
+    fn part_two(&self) -> Result<usize> {
+        Ok(part_two(self.floor.clone(), self.days))
+    }
+}
+
+fn part_two(mut floor: Sparse<Point>, days: usize) -> usize {
+    let neighbours = |(x, y): Point| HEX.iter().map(move |(dx, dy)| (x + dx, y + dy));
+
+    for _ in 0..days {
+        // A black tile with 0 or 3+ black neighbours turns white, and a white
+        // tile with 2 black neighbours turns black
+        floor = floor.step(neighbours, |black, n| n == 2 || (black && n == 1));
+    }
+
+    floor.len()
+}
+
+fn build_floor(input: &str) -> Result<Sparse<Point>> {
+    let tiles = Text::new(input).parse_lines(coordinates)?;
+
+    let mut floor = Sparse::new();
+    for coords in tiles {
+        floor.toggle(coords);
+    }
+
+    Ok(floor)
+}
+
+pub fn coordinates<'a>(tile: impl Into<Text<'a>>) -> Result<Point> {
+    // Hexagonal grid, using axial coordinates (see `grid::HEX`)
+    // E-W = x-axis; NE-SW = y-axis; NW-SE = diagonal
+    // E => +x, N => +y (so e.g. NW is -x, +y)
+    let mut x = 0;
+    let mut y = 0;
+    let tile = tile.into();
+    let mut instructions = tile.chars();
+    while let Some((at, c)) = instructions.next() {
+        match c {
+            'e' => x += 1,
+            'w' => x -= 1,
+            n_s => {
+                let e_w = instructions.next().map_or(' ', |(_, c)| c);
+                match (n_s, e_w) {
+                    ('n', 'e') => y += 1,
+                    ('n', 'w') => {
+                        x -= 1;
+                        y += 1;
+                    }
+                    ('s', 'e') => {
+                        x += 1;
+                        y -= 1;
+                    }
+                    ('s', 'w') => y -= 1,
+                    (a, b) => {
+                        return Err(at.error(format!(
+                            "Can't understand direction {:?}",
+                            format!("{}{}", a, b).trim_end()
+                        )))
+                    }
+                }
+            }
+        }
+    }
+    Ok((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let error = DayTwentyFour::parse("esew\nnwwswn").err().unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 2, column 6: Can't understand direction \"n\""
+        );
+        assert!(DayTwentyFour::parse("nwxe").is_err());
+    }
+}
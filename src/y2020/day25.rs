@@ -0,0 +1,119 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::solution::Solution;
+
+const SUBJECT: u64 = 7;
+const CEIL: u64 = 20201227;
+
+pub struct DayTwentyFive {
+    card_pub: u64,
+    door_pub: u64,
+}
+
+impl Solution for DayTwentyFive {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    const HAS_PART_TWO: bool = false;
+
+    fn parse(input: &str) -> Result<Self> {
+        // Card's public key, then the door's
+        let keys = Text::new(input).non_empty_lines();
+        if keys.len() != 2 {
+            return Err(Error::parse(format!(
+                "Expected 2 keys, found {} lines",
+                keys.len()
+            )));
+        }
+
+        // Anything outside this range can't come out of the transform
+        let key = |i: usize| -> Result<u64> {
+            match keys[i].num()? {
+                key if 0 < key && key < CEIL => Ok(key),
+                key => {
+                    Err(keys[i].error(format!("Key {} must be between 1 and {}", key, CEIL - 1)))
+                }
+            }
+        };
+
+        Ok(DayTwentyFive {
+            card_pub: key(0)?,
+            door_pub: key(1)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64> {
+        Ok(part_one(self.card_pub, self.door_pub))
+    }
+
+    fn part_two(&self) -> Result<u64> {
+        Err(Error::unsupported("there's no part two on Christmas Day"))
+    }
+}
+
+fn part_one(card_pub: u64, door_pub: u64) -> u64 {
+    let door_loop = find_loop(door_pub);
+    // println!("Door loop {:}", door_loop);
+
+    transform(card_pub, door_loop)
+}
+
+pub fn transform(subject: u64, loops: u64) -> u64 {
+    let mut val = 1;
+    for _ in 0..loops {
+        val *= subject;
+        val %= CEIL;
+    }
+
+    val
+}
+
+// The loop size which transforms the subject number 7 into this key
+pub fn find_loop(pub_key: u64) -> u64 {
+    let mut loops = 1;
+    let mut val = 1;
+
+    loop {
+        val *= SUBJECT;
+        val %= CEIL;
+        if val == pub_key {
+            return loops;
+        }
+
+        loops += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        assert!(transform(SUBJECT, 8) == 5764801);
+        assert!(transform(SUBJECT, 11) == 17807724);
+    }
+
+    #[test]
+    fn test_loop() {
+        assert!(find_loop(5764801) == 8);
+        assert!(find_loop(17807724) == 11);
+    }
+
+    #[test]
+    fn test_one() {
+        assert!(part_one(5764801, 17807724) == 14897079);
+        assert!(part_one(17807724, 5764801) == 14897079);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(DayTwentyFive::parse("5764801\n17807724").is_ok());
+        let error = DayTwentyFive::parse("5764801\n20201227").err().unwrap();
+        assert!(
+            error.to_string()
+                == "Parse error at line 2: Key 20201227 must be between 1 and 20201226"
+        );
+        assert!(DayTwentyFive::parse("5764801").is_err());
+    }
+}
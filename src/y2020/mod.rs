@@ -0,0 +1,62 @@
+//! Advent of Code 2020
+
+use crate::registry::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+const YEAR: u32 = 2020;
+
+/// All this year's days, in order.  `new-day` adds to this list.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day01::DayOne>(YEAR, 1),
+        Day::new::<day02::DayTwo>(YEAR, 2),
+        Day::new::<day03::DayThree>(YEAR, 3),
+        Day::new::<day04::DayFour>(YEAR, 4),
+        Day::new::<day05::DayFive>(YEAR, 5),
+        Day::new::<day06::DaySix>(YEAR, 6),
+        Day::new::<day07::DaySeven>(YEAR, 7),
+        Day::new::<day08::DayEight>(YEAR, 8),
+        Day::new::<day09::DayNine>(YEAR, 9),
+        Day::new::<day10::DayTen>(YEAR, 10),
+        Day::new::<day11::DayEleven>(YEAR, 11),
+        Day::new::<day12::DayTwelve>(YEAR, 12),
+        Day::new::<day13::DayThirteen>(YEAR, 13),
+        Day::new::<day14::DayFourteen>(YEAR, 14),
+        Day::new::<day15::DayFifteen>(YEAR, 15),
+        Day::new::<day16::DaySixteen>(YEAR, 16),
+        Day::new::<day17::DaySeventeen>(YEAR, 17),
+        Day::new::<day18::DayEighteen>(YEAR, 18),
+        Day::new::<day19::DayNineteen>(YEAR, 19),
+        Day::new::<day20::DayTwenty>(YEAR, 20),
+        Day::new::<day21::DayTwentyOne>(YEAR, 21),
+        Day::new::<day22::DayTwentyTwo>(YEAR, 22),
+        Day::new::<day23::DayTwentyThree>(YEAR, 23),
+        Day::new::<day24::DayTwentyFour>(YEAR, 24),
+        Day::new::<day25::DayTwentyFive>(YEAR, 25),
+    ]
+}